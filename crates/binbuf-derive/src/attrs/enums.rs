@@ -1,21 +1,37 @@
 use proc_macro2::Span;
 use syn::{Error, LitStr};
 
-use crate::attrs::{RawContainerAttrs, TryFromAttrs};
+use crate::attrs::{ByteOrder, Endianness, RawContainerAttrs, RawVariantAttrs, TryFromAttrs};
 
 #[derive(Debug)]
 pub struct EnumReadAttrs {
-    pub error: LitStr,
-    pub endianness: LitStr,
+    /// A custom error type. If set, `TryRead` is implemented instead of
+    /// `Read`.
+    pub error: Option<LitStr>,
+    pub endianness: Endianness,
+
+    /// The byte order of the tag and the default byte order of all fields.
+    pub byte_order: Option<ByteOrder>,
     pub repr: LitStr,
+
+    /// The tag selecting the variant is supplied by the caller instead of
+    /// being read in front of the payload.
+    pub external_tag: bool,
+
+    /// Custom where predicates which replace the automatically added trait
+    /// bounds of type parameters.
+    pub bound: Option<LitStr>,
 }
 
 impl Default for EnumReadAttrs {
     fn default() -> Self {
         Self {
-            error: LitStr::new("::binbuf::read::Error", Span::call_site()),
-            endianness: LitStr::new("both", Span::call_site()),
+            error: None,
+            endianness: Endianness::default(),
+            byte_order: None,
             repr: LitStr::new("u8", Span::call_site()),
+            external_tag: false,
+            bound: None,
         }
     }
 }
@@ -27,23 +43,40 @@ impl TryFromAttrs<RawContainerAttrs> for EnumReadAttrs {
     {
         match value {
             Some(attrs) => {
-                let mut enum_attrs = Self::default();
-
-                if attrs.endianness.is_some() {
-                    enum_attrs.endianness = attrs.endianness.unwrap();
-                }
-
-                if attrs.error.is_some() {
-                    enum_attrs.error = attrs.error.unwrap();
-                }
+                let mut enum_attrs = Self {
+                    error: attrs.error,
+                    endianness: Endianness::parse(attrs.endianness)?,
+                    byte_order: ByteOrder::parse(&attrs.big, &attrs.little, span)?,
+                    bound: attrs.read_bound.or(attrs.bound),
+                    ..Default::default()
+                };
 
                 if attrs.repr.is_some() {
                     let repr = attrs.repr.unwrap();
-                    if !["u8", "u16", "u32", "u64", "u128"].contains(&repr.value().as_str()) {
+                    if ![
+                        "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128",
+                    ]
+                    .contains(&repr.value().as_str())
+                    {
                         return Err(
-                            Error::new(span, "Only u8, u16, u32, u64 and u128 are supported enum variant representations")
+                            Error::new(span, "Only u8, u16, u32, u64, u128, i8, i16, i32, i64 and i128 are supported enum variant representations")
                         );
                     }
+
+                    enum_attrs.repr = repr;
+                }
+
+                if let Some(tag) = attrs.tag {
+                    enum_attrs.external_tag = match tag.value().as_str() {
+                        "leading" => false,
+                        "external" => true,
+                        _ => {
+                            return Err(Error::new(
+                                tag.span(),
+                                "Invalid tag value, expected 'leading' or 'external'",
+                            ))
+                        }
+                    };
                 }
 
                 Ok(enum_attrs)
@@ -52,3 +85,68 @@ impl TryFromAttrs<RawContainerAttrs> for EnumReadAttrs {
         }
     }
 }
+
+#[derive(Debug)]
+pub struct EnumWriteAttrs {
+    pub endianness: Endianness,
+    pub byte_order: Option<ByteOrder>,
+    pub repr: LitStr,
+    pub external_tag: bool,
+
+    /// Custom where predicates which replace the automatically added trait
+    /// bounds of type parameters.
+    pub bound: Option<LitStr>,
+}
+
+impl Default for EnumWriteAttrs {
+    fn default() -> Self {
+        Self {
+            endianness: Endianness::default(),
+            byte_order: None,
+            repr: EnumReadAttrs::default().repr,
+            external_tag: false,
+            bound: None,
+        }
+    }
+}
+
+impl TryFromAttrs<RawContainerAttrs> for EnumWriteAttrs {
+    fn try_from(value: Option<RawContainerAttrs>, span: Span) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let bound = value
+            .as_ref()
+            .and_then(|attrs| attrs.write_bound.clone().or_else(|| attrs.bound.clone()));
+
+        // The representation is validated the same way for both derives
+        let attrs = <EnumReadAttrs as TryFromAttrs<_>>::try_from(value, span)?;
+        Ok(Self {
+            endianness: attrs.endianness,
+            byte_order: attrs.byte_order,
+            repr: attrs.repr,
+            external_tag: attrs.external_tag,
+            bound,
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct VariantAttrs {
+    /// The variant holds all values which don't match any other variant.
+    pub other: bool,
+}
+
+impl TryFromAttrs<RawVariantAttrs> for VariantAttrs {
+    fn try_from(value: Option<RawVariantAttrs>, _span: Span) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        match value {
+            Some(attrs) => Ok(Self {
+                other: attrs.other.value(),
+            }),
+            None => Ok(Self::default()),
+        }
+    }
+}
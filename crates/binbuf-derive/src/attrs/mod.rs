@@ -1,6 +1,7 @@
-use proc_macro2::Span;
-use structmeta::StructMeta;
-use syn::{parse::Parse, spanned::Spanned, Attribute, Error, LitBool, LitStr};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use structmeta::{Flag, StructMeta};
+use syn::{parse::Parse, spanned::Spanned, Attribute, Error, LitBool, LitInt, LitStr};
 
 mod enums;
 mod structs;
@@ -35,11 +36,87 @@ pub trait AttrsParse: Sized + Parse {
     }
 }
 
+/// The byte orders supported by a container, set by the `endianness`
+/// container attribute.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum Endianness {
+    Big,
+    Little,
+    #[default]
+    Both,
+}
+
+impl Endianness {
+    pub fn parse(value: Option<LitStr>) -> Result<Self, Error> {
+        let value = match value {
+            Some(value) => value,
+            None => return Ok(Self::default()),
+        };
+
+        match value.value().as_str() {
+            "big" => Ok(Self::Big),
+            "little" => Ok(Self::Little),
+            "both" => Ok(Self::Both),
+            _ => Err(Error::new(
+                value.span(),
+                "Invalid endianness value, expected 'big', 'little' or 'both'",
+            )),
+        }
+    }
+}
+
+impl ToTokens for Endianness {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Big => quote! { ::binbuf::SupportedEndianness::Big },
+            Self::Little => quote! { ::binbuf::SupportedEndianness::Little },
+            Self::Both => quote! { ::binbuf::SupportedEndianness::Both },
+        })
+    }
+}
+
+/// The byte order a container or field is pinned to using the `big` or
+/// `little` attribute, regardless of the caller's endianness.
+#[derive(Debug, Clone, Copy)]
+pub enum ByteOrder {
+    Big,
+    Little,
+}
+
+impl ByteOrder {
+    pub fn parse(big: &Flag, little: &Flag, span: Span) -> Result<Option<Self>, Error> {
+        match (big.value(), little.value()) {
+            (true, true) => Err(Error::new(
+                span,
+                "Setting both 'big' and 'little' is not supported",
+            )),
+            (true, false) => Ok(Some(Self::Big)),
+            (false, true) => Ok(Some(Self::Little)),
+            (false, false) => Ok(None),
+        }
+    }
+}
+
+impl ToTokens for ByteOrder {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Big => quote! { ::binbuf::BigEndian },
+            Self::Little => quote! { ::binbuf::LittleEndian },
+        })
+    }
+}
+
 #[derive(Debug, StructMeta)]
 pub struct RawContainerAttrs {
     endianness: Option<LitStr>,
+    big: Flag,
+    little: Flag,
     error: Option<LitStr>,
     repr: Option<LitStr>,
+    tag: Option<LitStr>,
+    bound: Option<LitStr>,
+    read_bound: Option<LitStr>,
+    write_bound: Option<LitStr>,
 }
 
 impl AttrsParse for RawContainerAttrs {}
@@ -49,7 +126,21 @@ pub struct RawFieldAttrs {
     skip_write: Option<LitBool>,
     skip_read: Option<LitBool>,
     skip: Option<LitBool>,
+    big: Flag,
+    little: Flag,
+    cstring: Flag,
+    max_len: Option<LitInt>,
+    terminator: Option<LitStr>,
+    padded: Option<LitInt>,
+    pad: Option<LitInt>,
     // order: Option<LitInt>,
 }
 
 impl AttrsParse for RawFieldAttrs {}
+
+#[derive(Debug, StructMeta)]
+pub struct RawVariantAttrs {
+    other: Flag,
+}
+
+impl AttrsParse for RawVariantAttrs {}
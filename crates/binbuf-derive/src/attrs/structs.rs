@@ -1,41 +1,63 @@
 use proc_macro2::Span;
-use syn::{Error, LitBool, LitStr};
+use syn::{Error, LitBool, LitInt, LitStr};
 
-use crate::attrs::{RawContainerAttrs, RawFieldAttrs, TryFromAttrs};
+use crate::attrs::{ByteOrder, Endianness, RawContainerAttrs, RawFieldAttrs, TryFromAttrs};
 
+#[derive(Default)]
 pub struct StructReadAttrs {
-    pub error: LitStr,
-    pub endianness: LitStr,
-}
+    /// A custom error type. If set, `TryRead` is implemented instead of
+    /// `Read`.
+    pub error: Option<LitStr>,
+    pub endianness: Endianness,
 
-impl Default for StructReadAttrs {
-    fn default() -> Self {
-        Self {
-            error: LitStr::new("binbuf::read::ReadError", Span::call_site()),
-            endianness: LitStr::new("both", Span::call_site()),
-        }
-    }
+    /// The default byte order of all fields.
+    pub byte_order: Option<ByteOrder>,
+
+    /// Custom where predicates which replace the automatically added trait
+    /// bounds of type parameters.
+    pub bound: Option<LitStr>,
 }
 
 impl TryFromAttrs<RawContainerAttrs> for StructReadAttrs {
-    fn try_from(value: Option<RawContainerAttrs>, _span: Span) -> Result<Self, Error>
+    fn try_from(value: Option<RawContainerAttrs>, span: Span) -> Result<Self, Error>
     where
         Self: Sized,
     {
         match value {
-            Some(attrs) => {
-                let mut struct_attrs = Self::default();
+            Some(attrs) => Ok(Self {
+                error: attrs.error,
+                endianness: Endianness::parse(attrs.endianness)?,
+                byte_order: ByteOrder::parse(&attrs.big, &attrs.little, span)?,
+                bound: attrs.read_bound.or(attrs.bound),
+            }),
+            None => Ok(Self::default()),
+        }
+    }
+}
 
-                if attrs.endianness.is_some() {
-                    struct_attrs.endianness = attrs.endianness.unwrap();
-                }
+#[derive(Debug, Default)]
+pub struct StructWriteAttrs {
+    pub endianness: Endianness,
 
-                if attrs.error.is_some() {
-                    struct_attrs.error = attrs.error.unwrap();
-                }
+    /// The default byte order of all fields.
+    pub byte_order: Option<ByteOrder>,
 
-                Ok(struct_attrs)
-            }
+    /// Custom where predicates which replace the automatically added trait
+    /// bounds of type parameters.
+    pub bound: Option<LitStr>,
+}
+
+impl TryFromAttrs<RawContainerAttrs> for StructWriteAttrs {
+    fn try_from(value: Option<RawContainerAttrs>, span: Span) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        match value {
+            Some(attrs) => Ok(Self {
+                endianness: Endianness::parse(attrs.endianness)?,
+                byte_order: ByteOrder::parse(&attrs.big, &attrs.little, span)?,
+                bound: attrs.write_bound.or(attrs.bound),
+            }),
             None => Ok(Self::default()),
         }
     }
@@ -45,6 +67,11 @@ impl TryFromAttrs<RawContainerAttrs> for StructReadAttrs {
 pub struct FieldAttrs {
     pub skip_write: LitBool,
     pub skip_read: LitBool,
+    pub encoding: FieldEncoding,
+
+    /// The byte order the field is pinned to, which overrides the byte order
+    /// of the container.
+    pub byte_order: Option<ByteOrder>,
 }
 
 impl Default for FieldAttrs {
@@ -52,10 +79,30 @@ impl Default for FieldAttrs {
         Self {
             skip_write: LitBool::new(false, Span::call_site()),
             skip_read: LitBool::new(false, Span::call_site()),
+            encoding: FieldEncoding::default(),
+            byte_order: None,
         }
     }
 }
 
+/// The encoding of byte string fields, selected by the `cstring` and
+/// `padded` field attributes.
+#[derive(Debug, Default)]
+pub enum FieldEncoding {
+    /// The field is read and written using its own trait impls.
+    #[default]
+    Default,
+
+    /// The field is a NUL-terminated string.
+    CString {
+        max_len: Option<LitInt>,
+        optional: bool,
+    },
+
+    /// The field is a fixed-width string padded with `pad` bytes.
+    Padded { width: LitInt, pad: LitInt },
+}
+
 impl TryFromAttrs<RawFieldAttrs> for FieldAttrs {
     fn try_from(value: Option<RawFieldAttrs>, span: Span) -> Result<Self, Error>
     where
@@ -86,9 +133,65 @@ impl TryFromAttrs<RawFieldAttrs> for FieldAttrs {
                     field_attrs.skip_read = LitBool::new(true, span);
                 }
 
+                field_attrs.byte_order = ByteOrder::parse(&attrs.big, &attrs.little, span)?;
+                field_attrs.encoding = FieldEncoding::try_from(attrs, span)?;
                 Ok(field_attrs)
             }
             None => Ok(Self::default()),
         }
     }
 }
+
+impl FieldEncoding {
+    fn try_from(attrs: RawFieldAttrs, span: Span) -> Result<Self, Error> {
+        if attrs.cstring.value() && attrs.padded.is_some() {
+            return Err(Error::new(
+                span,
+                "Setting both 'cstring' and 'padded' is not supported",
+            ));
+        }
+
+        if !attrs.cstring.value() && (attrs.max_len.is_some() || attrs.terminator.is_some()) {
+            return Err(Error::new(
+                span,
+                "'max_len' and 'terminator' can only be used with 'cstring'",
+            ));
+        }
+
+        if attrs.padded.is_none() && attrs.pad.is_some() {
+            return Err(Error::new(span, "'pad' can only be used with 'padded'"));
+        }
+
+        if attrs.cstring.value() {
+            let optional = match attrs.terminator {
+                Some(terminator) => match terminator.value().as_str() {
+                    "required" => false,
+                    "optional" => true,
+                    _ => {
+                        return Err(Error::new(
+                            terminator.span(),
+                            "Only 'required' and 'optional' are supported terminator modes",
+                        ))
+                    }
+                },
+                None => false,
+            };
+
+            return Ok(Self::CString {
+                max_len: attrs.max_len,
+                optional,
+            });
+        }
+
+        if let Some(width) = attrs.padded {
+            return Ok(Self::Padded {
+                width,
+                pad: attrs
+                    .pad
+                    .unwrap_or_else(|| LitInt::new("0", Span::call_site())),
+            });
+        }
+
+        Ok(Self::Default)
+    }
+}
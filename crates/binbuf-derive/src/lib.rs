@@ -67,7 +67,7 @@ pub fn readable_macro_derive(input: TokenStream) -> TokenStream {
 /// assert_eq!(b, vec![69, 88, 65, 77]);
 /// assert_eq!(n, 4);
 /// ```
-#[proc_macro_derive(Write)]
+#[proc_macro_derive(Write, attributes(binbuf))]
 pub fn writeable_macro_derive(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as DeriveInput);
 
@@ -1,23 +1,24 @@
-use proc_macro2::{Ident, Literal, Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 use syn::{
-    punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, DataEnum, DataStruct,
-    DeriveInput, Error, ExprPath, Field, Result as SynResult,
+    spanned::Spanned, Attribute, DataEnum, DataStruct, DeriveInput, Error, ExprPath, Fields,
+    Generics, Lifetime, LitStr, Result as SynResult, WherePredicate,
 };
 
 use crate::{
     attrs::{
-        AttrsParse, EnumReadAttrs, FieldAttrs, RawContainerAttrs, RawFieldAttrs, StructReadAttrs,
+        AttrsParse, ByteOrder, Endianness, EnumReadAttrs, FieldAttrs, FieldEncoding,
+        RawContainerAttrs, RawFieldAttrs, StructReadAttrs,
     },
-    shared,
+    shared::{self, EnumVariant},
 };
 
 /// Expand the `Readable` derive macro by returning the generated
 /// [`TokenStream`].
 pub fn expand(input: DeriveInput) -> SynResult<TokenStream> {
     match input.data {
-        syn::Data::Struct(s) => expand_struct(s, &input.ident, input.attrs),
-        syn::Data::Enum(e) => expand_enum(e, &input.ident, input.attrs),
+        syn::Data::Struct(s) => expand_struct(s, &input.ident, input.attrs, &input.generics),
+        syn::Data::Enum(e) => expand_enum(e, &input.ident, input.attrs, &input.generics),
         syn::Data::Union(_) => Err(Error::new(
             Span::call_site(),
             "The Readable derive macro can only be used with structs or enums",
@@ -29,39 +30,72 @@ fn expand_struct(
     struct_data: DataStruct,
     struct_name: &Ident,
     struct_attrs: Vec<Attribute>,
+    generics: &Generics,
 ) -> SynResult<TokenStream> {
-    // Extract all named fields. This will return an error if there are unnamed
-    // fields present
-    let named_fields = match shared::extract_named_fields_from_struct(struct_data) {
-        Some(f) => f.named,
-        None => {
-            return Err(Error::new(
-                Span::call_site(),
-                "The target struct only supports named fields",
-            ))
-        }
-    };
+    // Parse struct attributes
+    let struct_attrs = RawContainerAttrs::parse::<StructReadAttrs>(struct_attrs)?;
 
-    if named_fields.is_empty() {
-        return Ok(quote! {});
+    // Structs with a lifetime parameter borrow from the input and thus
+    // implement ReadRef instead of Read
+    let mut lifetimes = generics.lifetimes();
+    let lifetime = lifetimes.next().map(|l| &l.lifetime);
+
+    if let Some(l) = lifetimes.next() {
+        return Err(Error::new(
+            l.span(),
+            "The target struct supports at most one lifetime parameter",
+        ));
     }
 
-    // Parse struct attributes
-    let struct_attrs = RawContainerAttrs::parse::<StructReadAttrs>(struct_attrs)?;
+    // Validate the struct args
+    let error: Option<ExprPath> = struct_attrs.error.map(|e| e.parse()).transpose()?;
 
     // TODO (Techassi): Make this always a loop to simplify field attr parsing
-    let read_inner = match gen_struct_fields(named_fields) {
-        Ok(ts) => ts,
-        Err(err) => return Err(err),
-    };
+    let read_inner = gen_struct_fields(
+        &struct_data.fields,
+        lifetime,
+        quote! { Self },
+        error.is_some(),
+        struct_attrs.byte_order,
+    )?;
+
+    if let Some(lifetime) = lifetime {
+        if error.is_some() {
+            return Err(Error::new(
+                lifetime.span(),
+                "Custom errors are not supported for structs with a lifetime parameter",
+            ));
+        }
 
-    // Validate the struct args
-    let readable_error: ExprPath = struct_attrs.error.parse()?;
+        let bound = quote! { ::binbuf::read::ReadRef<#lifetime> };
+        let generics = shared::add_trait_bounds(generics, bound, struct_attrs.bound.as_ref())?;
+
+        return Ok(shared::gen_read_ref_impl(
+            struct_name,
+            &generics,
+            lifetime,
+            read_inner,
+            struct_attrs.endianness,
+        ));
+    }
+
+    // Structs with a custom error read their fields with TryRead and convert
+    // the errors of the fields into the custom error
+    let generics = add_read_bounds(generics, struct_attrs.bound.as_ref(), error.as_ref())?;
+
+    if let Some(error) = error {
+        return Ok(shared::gen_try_read_impl(
+            struct_name,
+            &generics,
+            read_inner,
+            &error,
+            struct_attrs.endianness,
+        ));
+    }
 
     // Generate trait impls
-    let readable_impl = shared::gen_readable_impl(struct_name, read_inner, readable_error);
-    // let readable_verify_impl =
-    //     shared::gen_readable_verify_impl(struct_name, struct_attrs.endianness)?;
+    let readable_impl =
+        shared::gen_readable_impl(struct_name, &generics, read_inner, struct_attrs.endianness);
 
     Ok(quote! {
         #readable_impl
@@ -72,6 +106,7 @@ fn expand_enum(
     enum_data: DataEnum,
     enum_name: &Ident,
     enum_attrs: Vec<Attribute>,
+    generics: &Generics,
 ) -> SynResult<TokenStream> {
     // If there are no variants, we don't generate code
     if enum_data.variants.is_empty() {
@@ -80,19 +115,82 @@ fn expand_enum(
 
     // Parse enum attributes
     let enum_attrs = RawContainerAttrs::parse::<EnumReadAttrs>(enum_attrs)?;
-    let error: ExprPath = enum_attrs.error.parse()?;
+    let custom_error: Option<ExprPath> = enum_attrs.error.map(|e| e.parse()).transpose()?;
     let repr: ExprPath = enum_attrs.repr.parse()?;
     // println!("{enum_attrs:?}");
 
+    let repr_type = repr.path.get_ident().unwrap().to_string();
+    let variants = shared::extract_enum_variants(&enum_data, &repr_type)?;
+    shared::check_other_variant(&variants, enum_attrs.external_tag)?;
+
+    // Enums always own their data, the variants can't borrow from the input
+    if let Some(l) = generics.lifetimes().next() {
+        return Err(Error::new(
+            l.span(),
+            "Lifetime parameters are not supported for enums",
+        ));
+    }
+
+    let generics = add_read_bounds(generics, enum_attrs.bound.as_ref(), custom_error.as_ref())?;
+
+    let error = match &custom_error {
+        Some(error) => error.clone(),
+        None => syn::parse_quote! { ::binbuf::read::Error },
+    };
+
+    // The tag uses the byte order of the enum, if it is pinned
+    let tag_endianness = shared::gen_endianness(enum_attrs.byte_order);
+
+    // Enums with variants carrying data, or with an external tag, read the
+    // payload of the variant selected by the tag
+    if enum_attrs.external_tag || !shared::is_fieldless(&variants) {
+        let read_payload_impl = gen_read_payload_impl_enum(
+            enum_name,
+            &generics,
+            &variants,
+            &repr,
+            custom_error.as_ref(),
+            enum_attrs.endianness,
+            enum_attrs.byte_order,
+        )?;
+
+        if enum_attrs.external_tag {
+            return Ok(read_payload_impl);
+        }
+
+        let read_inner = quote! {
+            let tag = #repr::read::<#tag_endianness>(buf)?;
+            <Self as ::binbuf::read::ReadPayload>::read_payload::<E>(tag, buf)
+        };
+
+        let readable_impl = gen_enum_readable_impl(
+            enum_name,
+            &generics,
+            read_inner,
+            custom_error.as_ref(),
+            enum_attrs.endianness,
+        );
+
+        return Ok(quote! {
+            #read_payload_impl
+            #readable_impl
+        });
+    }
+
+    // The TryFrom impl uses the custom error, if any
     let read_inner = quote! {
-        Self::try_from(#repr::read::<E>(buf)?)
+        Self::try_from(#repr::read::<#tag_endianness>(buf)?)
     };
 
     // Implement From<REPR> for ENUM
-    let from_repr_impl = gen_from_repr_impl_enum(enum_name, &enum_data, &enum_attrs)?;
-    let readable_impl = shared::gen_readable_impl(enum_name, read_inner, error);
-    // let readable_verify_impl = shared::gen_readable_verify_impl(enum_name, enum_attrs.endianness)?;
-    // let from_enum_impl = gen_from_enum_impl_repr(enum_name, &enum_data, &enum_attrs)?;
+    let from_repr_impl = gen_from_repr_impl_enum(enum_name, &generics, &variants, &repr, &error);
+    let readable_impl = gen_enum_readable_impl(
+        enum_name,
+        &generics,
+        read_inner,
+        custom_error.as_ref(),
+        enum_attrs.endianness,
+    );
 
     Ok(quote! {
         #from_repr_impl
@@ -100,142 +198,249 @@ fn expand_enum(
     })
 }
 
+/// This generates the Read trait impl, or the TryRead trait impl if the enum
+/// uses a custom error type.
+fn gen_enum_readable_impl(
+    enum_name: &Ident,
+    generics: &Generics,
+    read_inner: TokenStream,
+    custom_error: Option<&ExprPath>,
+    endianness: Endianness,
+) -> TokenStream {
+    match custom_error {
+        Some(error) => {
+            shared::gen_try_read_impl(enum_name, generics, read_inner, error, endianness)
+        }
+        None => shared::gen_readable_impl(enum_name, generics, read_inner, endianness),
+    }
+}
+
+/// Adds the trait bounds required to read the type parameters of a struct or
+/// enum. With a custom error, type parameters are read using `TryRead` and
+/// their errors have to convert into the custom error.
+fn add_read_bounds(
+    generics: &Generics,
+    custom_bound: Option<&LitStr>,
+    error: Option<&ExprPath>,
+) -> SynResult<Generics> {
+    let error = match error {
+        Some(error) => error,
+        None => {
+            let bound = quote! { ::binbuf::read::Read };
+            return shared::add_trait_bounds(generics, bound, custom_bound);
+        }
+    };
+
+    let bound = quote! { ::binbuf::read::TryRead };
+    let mut generics = shared::add_trait_bounds(generics, bound, custom_bound)?;
+
+    if custom_bound.is_none() {
+        let predicates: Vec<WherePredicate> = generics
+            .type_params()
+            .map(|param| {
+                let ident = &param.ident;
+                syn::parse_quote! {
+                    #error: ::std::convert::From<<#ident as ::binbuf::read::TryRead>::Error>
+                }
+            })
+            .collect();
+
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+
+    Ok(generics)
+}
+
 fn gen_from_repr_impl_enum(
     enum_name: &Ident,
-    enum_data: &DataEnum,
-    enum_attrs: &EnumReadAttrs,
-) -> SynResult<TokenStream> {
-    let error: ExprPath = enum_attrs.error.parse()?;
-    let repr: ExprPath = enum_attrs.repr.parse()?;
+    generics: &Generics,
+    variants: &[EnumVariant],
+    repr: &ExprPath,
+    error: &ExprPath,
+) -> TokenStream {
+    let mut arms: Vec<TokenStream> = Vec::new();
+    let mut fallback = quote! {
+        _ => Err(::binbuf::read::Error::InvalidData.into()),
+    };
 
-    let repr_type = repr.path.get_ident().unwrap().to_string();
+    for variant in variants {
+        match variant {
+            EnumVariant::Value(variant, variant_value) => {
+                let variant_ident = &variant.ident;
+                arms.push(quote! {
+                    #variant_value => Ok(Self::#variant_ident),
+                })
+            }
+            // Unknown values are preserved by the 'other' variant
+            EnumVariant::Other(variant_ident) => {
+                fallback = quote! {
+                    other => Ok(Self::#variant_ident(other)),
+                }
+            }
+        }
+    }
+
+    arms.push(fallback);
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let mut variants: Vec<TokenStream> = Vec::new();
-
-    for (index, variant) in (0_u128..).zip((&enum_data.variants).into_iter()) {
-        let variant_ident = &variant.ident;
-        let variant_value = match repr_type.as_str() {
-            "u8" => Literal::u8_suffixed(index as u8),
-            "u16" => Literal::u16_suffixed(index as u16),
-            "u32" => Literal::u32_suffixed(index as u32),
-            "u64" => Literal::u64_suffixed(index as u64),
-            "u128" => Literal::u128_suffixed(index),
-            _ => {
-                return Err(Error::new(
-                    variant.span(),
-                    "Invalid variant representation type",
-                ))
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics TryFrom<#repr> for #enum_name #ty_generics #where_clause {
+            type Error = #error;
+
+            fn try_from(value: #repr) -> Result<Self, Self::Error> {
+                match value {
+                    #(#arms)*
+                }
             }
-        };
+        }
+    }
+}
 
-        variants.push(quote! {
-            #variant_value => Ok(Self::#variant_ident),
-        });
+/// This generates the ReadPayload trait impl, which reads the fields of the
+/// variant selected by a tag.
+fn gen_read_payload_impl_enum(
+    enum_name: &Ident,
+    generics: &Generics,
+    variants: &[EnumVariant],
+    repr: &ExprPath,
+    custom_error: Option<&ExprPath>,
+    endianness: Endianness,
+    byte_order: Option<ByteOrder>,
+) -> SynResult<TokenStream> {
+    let error = match custom_error {
+        Some(error) => quote! { #error },
+        None => quote! { ::binbuf::read::Error },
+    };
+
+    let mut arms: Vec<TokenStream> = Vec::new();
+    let mut fallback = quote! {
+        _ => Err(::binbuf::read::Error::InvalidData.into()),
+    };
+
+    for variant in variants {
+        match variant {
+            EnumVariant::Value(variant, variant_value) => {
+                let variant_ident = &variant.ident;
+                let read_fields = gen_struct_fields(
+                    &variant.fields,
+                    None,
+                    quote! { Self::#variant_ident },
+                    custom_error.is_some(),
+                    byte_order,
+                )?;
+
+                arms.push(quote! {
+                    #variant_value => {
+                        #read_fields
+                    }
+                })
+            }
+            // Unknown tags are preserved by the 'other' variant, which reads
+            // no payload
+            EnumVariant::Other(variant_ident) => {
+                fallback = quote! {
+                    other => Ok(Self::#variant_ident(other)),
+                }
+            }
+        }
     }
 
-    // TODO (Techassi): This should not be hardcoded. Introduce the possibility to specify an error variant
-    variants.push(quote! {
-        _ => Err(Self::Error::InvalidData),
-    });
+    arms.push(fallback);
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     Ok(quote! {
         #[automatically_derived]
-        impl TryFrom<#repr> for #enum_name {
+        impl #impl_generics ::binbuf::read::ReadPayload for #enum_name #ty_generics #where_clause {
+            type Tag = #repr;
             type Error = #error;
 
-            fn try_from(value: #repr) -> Result<Self, Self::Error> {
-                match value {
-                    #(#variants)*
+            fn read_payload<E: ::binbuf::Endianness>(
+                tag: #repr,
+                buf: &mut ::binbuf::read::Reader,
+            ) -> ::std::result::Result<Self, #error> {
+                #endianness.verify_read::<E>()?;
+
+                match tag {
+                    #(#arms)*
                 }
             }
         }
     })
 }
 
-// fn gen_from_enum_impl_repr(
-//     enum_name: &Ident,
-//     enum_data: &DataEnum,
-//     enum_attrs: &EnumReadAttrs,
-// ) -> SynResult<TokenStream> {
-//     let repr: ExprPath = enum_attrs.repr.parse()?;
-//     let repr_type = repr.path.get_ident().unwrap().to_string();
-
-//     let mut variants: Vec<TokenStream> = Vec::new();
-
-//     for (index, variant) in (0_u128..).zip((&enum_data.variants).into_iter()) {
-//         let variant_ident = &variant.ident;
-//         let variant_value = match repr_type.as_str() {
-//             "u8" => Literal::u8_suffixed(index as u8),
-//             "u16" => Literal::u16_suffixed(index as u16),
-//             "u32" => Literal::u32_suffixed(index as u32),
-//             "u64" => Literal::u64_suffixed(index as u64),
-//             "u128" => Literal::u128_suffixed(index),
-//             _ => {
-//                 return Err(Error::new(
-//                     variant.span(),
-//                     "Invalid variant representation type",
-//                 ))
-//             }
-//         };
-
-//         variants.push(quote! {
-//             #enum_name::#variant_ident => #variant_value,
-//         });
-//     }
-
-//     Ok(quote! {
-//         impl From<#enum_name> for #repr {
-//             fn from(value: #enum_name) -> Self {
-//                 match value {
-//                     #(#variants)*
-//                 }
-//             }
-//         }
-//     })
-// }
-
-/// This generates code when there are multiple named fields in the struct.
-fn gen_struct_fields(fields: Punctuated<Field, Comma>) -> SynResult<TokenStream> {
+/// This generates code to read the fields of a struct or enum variant with
+/// named fields, unnamed fields or no fields at all. `path` is the path used
+/// to construct the value, like `Self` or `Self::Variant`. With `try_read`,
+/// the fields are read using `TryRead` to support custom errors. Fields which
+/// are not pinned to a byte order use the container's `byte_order`, if any.
+fn gen_struct_fields(
+    fields: &Fields,
+    lifetime: Option<&Lifetime>,
+    path: TokenStream,
+    try_read: bool,
+    byte_order: Option<ByteOrder>,
+) -> SynResult<TokenStream> {
     // Here we need ensure the ReadableMulti trait is implemented, how can we achieve that?
     // For now, we just generate a read call for each of the fields
     let mut funcs: Vec<TokenStream> = Vec::new();
     let mut inner: Vec<TokenStream> = Vec::new();
 
-    for field in fields {
+    for (index, field) in fields.iter().enumerate() {
         // Extract field attrs
         let attrs = RawFieldAttrs::parse::<FieldAttrs>(field.attrs.clone())?;
+        let endianness = shared::gen_endianness(attrs.byte_order.or(byte_order));
 
-        let field_name = field.ident.as_ref().unwrap();
-        let var_name = format_ident!("_gen_{}", field_name);
-
-        let field_type = match shared::extract_last_path_segment_ident(&field.ty) {
-            Some(t) => t,
-            None => {
-                return Err(Error::new(
-                    Span::call_site(),
-                    "Failed to extract ident from field type",
-                ))
-            }
+        let var_name = match &field.ident {
+            Some(field_name) => format_ident!("_gen_{}", field_name),
+            None => format_ident!("_gen_{}", index),
         };
 
         // Either generate a read function, or use default when skip_read=true
         let func = if attrs.skip_read.value {
-            shared::gen_default_func(&var_name, &field_type)
+            shared::gen_default_func(&var_name, &field.ty)
+        } else if let FieldEncoding::Default = attrs.encoding {
+            match lifetime {
+                Some(lifetime) => {
+                    shared::gen_read_ref_func(&var_name, &field.ty, lifetime, &endianness)
+                }
+                None if try_read => shared::gen_try_read_func(&var_name, &field.ty, &endianness),
+                None => {
+                    let field_type = match shared::extract_last_path_segment_ident(&field.ty) {
+                        Some(t) => t,
+                        None => {
+                            return Err(Error::new(
+                                Span::call_site(),
+                                "Failed to extract ident from field type",
+                            ))
+                        }
+                    };
+
+                    shared::gen_read_func(&var_name, &field_type, &endianness)
+                }
+            }
         } else {
-            shared::gen_read_func(&var_name, &field_type)
+            shared::gen_read_encoded_func(&var_name, &attrs.encoding, lifetime)
         };
 
         funcs.push(func);
-        inner.push(quote! {
-            #field_name: #var_name,
+        inner.push(match &field.ident {
+            Some(field_name) => quote! { #field_name: #var_name, },
+            None => quote! { #var_name, },
         })
     }
 
+    let constructor = match fields {
+        Fields::Named(_) => quote! { #path { #(#inner)* } },
+        Fields::Unnamed(_) => quote! { #path(#(#inner)*) },
+        Fields::Unit => quote! { #path },
+    };
+
     Ok(quote! {
         #(#funcs)*
 
-        return Ok(Self {
-            #(#inner)*
-        })
+        return Ok(#constructor)
     })
 }
@@ -1,5 +1,11 @@
-use proc_macro2::Ident;
-use syn::{Data, DataStruct, FieldsNamed, Type};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse::Parser, punctuated::Punctuated, spanned::Spanned, token::Comma, DataEnum, Expr, ExprLit,
+    ExprUnary, Fields, Generics, Lit, LitStr, Result, Type, UnOp, Variant, WherePredicate,
+};
+
+use crate::attrs::{AttrsParse, ByteOrder, RawVariantAttrs, VariantAttrs};
 
 mod read;
 mod write;
@@ -14,23 +20,6 @@ pub use write::*;
 //     pub ty: Ident,
 // }
 
-/// Checks if the provided [`Data`] is a struct and if yes, returns the struct
-/// data as [`DataStruct`].
-pub fn is_struct(data: Data) -> Option<DataStruct> {
-    match data {
-        Data::Struct(s) => Some(s),
-        _ => None,
-    }
-}
-
-/// Extracts all named fields of a struct
-pub fn extract_named_fields_from_struct(strukt: DataStruct) -> Option<FieldsNamed> {
-    match strukt.fields {
-        syn::Fields::Named(f) => Some(f),
-        _ => None,
-    }
-}
-
 /// Extract the last path segment ident. This is useful to retrieve the type
 /// of a struct field.
 pub fn extract_last_path_segment_ident(ty: &Type) -> Option<Ident> {
@@ -44,3 +33,184 @@ pub fn extract_last_path_segment_ident(ty: &Type) -> Option<Ident> {
         _ => None,
     }
 }
+
+/// Returns the endianness used to read or write a value. Values pinned to a
+/// byte order use it, all other values use the generic `E` of the caller.
+pub fn gen_endianness(byte_order: Option<ByteOrder>) -> TokenStream {
+    match byte_order {
+        Some(byte_order) => quote! { #byte_order },
+        None => quote! { E },
+    }
+}
+
+/// Adds the where predicates required by the generated impl to `generics`.
+/// By default, every type parameter is bound by `bound`. If the container
+/// provides a custom `bound` attribute, its predicates are used instead.
+pub fn add_trait_bounds(
+    generics: &Generics,
+    bound: TokenStream,
+    custom: Option<&LitStr>,
+) -> Result<Generics> {
+    let mut generics = generics.clone();
+
+    let predicates: Vec<WherePredicate> = match custom {
+        Some(custom) => Punctuated::<WherePredicate, Comma>::parse_terminated
+            .parse_str(&custom.value())
+            .map_err(|err| syn::Error::new(custom.span(), err))?
+            .into_iter()
+            .collect(),
+        None => generics
+            .type_params()
+            .map(|param| {
+                let ident = &param.ident;
+                syn::parse_quote! { #ident: #bound }
+            })
+            .collect(),
+    };
+
+    generics.make_where_clause().predicates.extend(predicates);
+
+    Ok(generics)
+}
+
+/// A variant of a derived enum.
+pub enum EnumVariant<'a> {
+    /// A variant which is encoded as the literal value, optionally followed
+    /// by its fields.
+    Value(&'a Variant, Literal),
+
+    /// The variant marked with `#[binbuf(other)]`, which holds the raw value
+    /// of all unknown values.
+    Other(&'a Ident),
+}
+
+/// Extracts the variants of an enum together with the values (tags) they are
+/// encoded as. Like Rust discriminants, variants without an explicit
+/// discriminant use the value of the previous variant plus one, starting at
+/// zero.
+pub fn extract_enum_variants<'a>(
+    enum_data: &'a DataEnum,
+    repr_type: &str,
+) -> Result<Vec<EnumVariant<'a>>> {
+    let mut variants = Vec::new();
+    let mut has_other = false;
+    let mut next = 0_i128;
+
+    for variant in &enum_data.variants {
+        let attrs = RawVariantAttrs::parse::<VariantAttrs>(variant.attrs.clone())?;
+
+        let value = match &variant.discriminant {
+            Some((_, expr)) => parse_discriminant(expr)?,
+            None => next,
+        };
+
+        if attrs.other {
+            if has_other {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "Only one variant can be marked as 'other'",
+                ));
+            }
+
+            if !matches!(&variant.fields, Fields::Unnamed(f) if f.unnamed.len() == 1) {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "The 'other' variant must have exactly one unnamed field holding the raw value",
+                ));
+            }
+
+            has_other = true;
+            variants.push(EnumVariant::Other(&variant.ident));
+        } else {
+            let literal = gen_repr_literal(repr_type, value, variant.span())?;
+            variants.push(EnumVariant::Value(variant, literal));
+        }
+
+        next = value
+            .checked_add(1)
+            .ok_or_else(|| syn::Error::new(variant.span(), "Enum discriminant overflowed"))?;
+    }
+
+    Ok(variants)
+}
+
+/// Returns if all variants apart from the 'other' variant are unit variants.
+/// Such enums are encoded as the plain tag and can be converted from and into
+/// their representation type.
+pub fn is_fieldless(variants: &[EnumVariant]) -> bool {
+    variants.iter().all(|variant| match variant {
+        EnumVariant::Value(variant, _) => matches!(variant.fields, Fields::Unit),
+        EnumVariant::Other(_) => true,
+    })
+}
+
+/// Rejects an 'other' variant in enums with variants carrying data and a
+/// leading tag. The 'other' variant reads no payload, so the payload of an
+/// unknown tag would be left in the input and read as the next value. With
+/// an external tag, the caller has to skip the payload using its own
+/// framing.
+pub fn check_other_variant(variants: &[EnumVariant], external_tag: bool) -> Result<()> {
+    if external_tag || is_fieldless(variants) {
+        return Ok(());
+    }
+
+    match variants.iter().find_map(|variant| match variant {
+        EnumVariant::Other(ident) => Some(ident),
+        EnumVariant::Value(..) => None,
+    }) {
+        Some(ident) => Err(syn::Error::new(
+            ident.span(),
+            "The 'other' variant requires tag = \"external\" on enums with variants carrying data",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Parses an explicit enum discriminant. Only (negative) integer literals
+/// are supported.
+fn parse_discriminant(expr: &Expr) -> Result<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_discriminant(expr).map(|value| -value),
+        Expr::Group(group) => parse_discriminant(&group.expr),
+        Expr::Paren(paren) => parse_discriminant(&paren.expr),
+        _ => Err(syn::Error::new(
+            expr.span(),
+            "Only integer literals are supported as enum discriminants",
+        )),
+    }
+}
+
+/// Returns `value` as a literal suffixed with the enum representation type,
+/// like `3u16`. Values which don't fit into the representation type return
+/// an error.
+fn gen_repr_literal(repr_type: &str, value: i128, span: Span) -> Result<Literal> {
+    let out_of_range = || {
+        syn::Error::new(
+            span,
+            format!("The value {value} doesn't fit into the variant representation {repr_type}"),
+        )
+    };
+
+    let literal = match repr_type {
+        "u8" => Literal::u8_suffixed(value.try_into().map_err(|_| out_of_range())?),
+        "u16" => Literal::u16_suffixed(value.try_into().map_err(|_| out_of_range())?),
+        "u32" => Literal::u32_suffixed(value.try_into().map_err(|_| out_of_range())?),
+        "u64" => Literal::u64_suffixed(value.try_into().map_err(|_| out_of_range())?),
+        "u128" => Literal::u128_suffixed(value.try_into().map_err(|_| out_of_range())?),
+        "i8" => Literal::i8_suffixed(value.try_into().map_err(|_| out_of_range())?),
+        "i16" => Literal::i16_suffixed(value.try_into().map_err(|_| out_of_range())?),
+        "i32" => Literal::i32_suffixed(value.try_into().map_err(|_| out_of_range())?),
+        "i64" => Literal::i64_suffixed(value.try_into().map_err(|_| out_of_range())?),
+        "i128" => Literal::i128_suffixed(value),
+        _ => return Err(syn::Error::new(span, "Invalid variant representation type")),
+    };
+
+    Ok(literal)
+}
@@ -1,15 +1,85 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::ExprPath;
+use syn::{ExprPath, Generics, Lifetime, Type};
 
-/// This generates a single read function call.
-pub fn gen_read_func(var_name: &Ident, field_type: &Ident) -> TokenStream {
+use crate::attrs::{Endianness, FieldEncoding};
+
+/// This generates a single read function call. `endianness` is either the
+/// generic `E` or the byte order the field is pinned to.
+pub fn gen_read_func(
+    var_name: &Ident,
+    field_type: &Ident,
+    endianness: &TokenStream,
+) -> TokenStream {
+    quote! {
+        let #var_name = #field_type::read::<#endianness>(buf)?;
+    }
+}
+
+/// This generates a single fallible read function call for containers with a
+/// custom error type. The error of the field is converted using `From`.
+pub fn gen_try_read_func(
+    var_name: &Ident,
+    field_type: &Type,
+    endianness: &TokenStream,
+) -> TokenStream {
+    quote! {
+        let #var_name = <#field_type as ::binbuf::read::TryRead>::try_read::<#endianness>(buf)?;
+    }
+}
+
+/// This generates a read function call for byte string fields which use the
+/// `cstring` or `padded` encoding. The field type has to implement
+/// `From<&[u8]>`. Structs with a lifetime parameter borrow the bytes from the
+/// input, so fields like `&'a [u8]` can be used.
+pub fn gen_read_encoded_func(
+    var_name: &Ident,
+    encoding: &FieldEncoding,
+    lifetime: Option<&Lifetime>,
+) -> TokenStream {
+    let (read_cstring, read_padded) = match lifetime {
+        Some(_) => (quote! { read_cstring_ref }, quote! { read_padded_ref }),
+        None => (quote! { read_cstring }, quote! { read_padded }),
+    };
+
+    let bytes = match encoding {
+        FieldEncoding::CString { max_len, optional } => {
+            let max_len = match max_len {
+                Some(max_len) => quote! { Some(#max_len) },
+                None => quote! { None },
+            };
+
+            let terminator = if *optional {
+                quote! { ::binbuf::read::Terminator::Optional }
+            } else {
+                quote! { ::binbuf::read::Terminator::Required }
+            };
+
+            quote! { buf.#read_cstring(#max_len, #terminator)? }
+        }
+        FieldEncoding::Padded { width, pad } => quote! { buf.#read_padded(#width, #pad)? },
+        FieldEncoding::Default => unreachable!("default encoded fields use gen_read_func"),
+    };
+
+    quote! {
+        let #var_name = ::std::convert::From::from(#bytes);
+    }
+}
+
+/// This generates a single borrowing read function call for structs with a
+/// lifetime parameter.
+pub fn gen_read_ref_func(
+    var_name: &Ident,
+    field_type: &Type,
+    lifetime: &Lifetime,
+    endianness: &TokenStream,
+) -> TokenStream {
     quote! {
-        let #var_name = #field_type::read::<E>(buf)?;
+        let #var_name = <#field_type as ::binbuf::read::ReadRef<#lifetime>>::read_ref::<#endianness>(buf)?;
     }
 }
 
-pub fn gen_default_func(var_name: &Ident, field_type: &Ident) -> TokenStream {
+pub fn gen_default_func(var_name: &Ident, field_type: &Type) -> TokenStream {
     quote! {
         let #var_name = <#field_type as ::std::default::Default>::default();
     }
@@ -18,9 +88,12 @@ pub fn gen_default_func(var_name: &Ident, field_type: &Ident) -> TokenStream {
 /// This generates the Readable trait impl.
 pub fn gen_readable_impl(
     struct_name: &Ident,
+    generics: &Generics,
     read_inner: TokenStream,
-    _error: ExprPath,
+    endianness: Endianness,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     let doc_header = format!(" Read [`{struct_name}`] from a [`ReadBuffer`].");
     let doc_func = format!(
         " let {} = {}::read::<BigEndian>(&mut buf).unwrap();",
@@ -30,7 +103,9 @@ pub fn gen_readable_impl(
 
     quote! {
         #[automatically_derived]
-        impl ::binbuf::read::Read for #struct_name {
+        impl #impl_generics ::binbuf::read::Read for #struct_name #ty_generics #where_clause {
+            const ENDIANNESS: ::binbuf::SupportedEndianness = #endianness;
+
             #[doc = #doc_header]
             ///
             /// ### Example
@@ -42,6 +117,53 @@ pub fn gen_readable_impl(
             #[doc = #doc_func]
             /// ```
             fn read<E: ::binbuf::Endianness>(buf: &mut ::binbuf::read::Reader) -> ::binbuf::read::Result<Self> {
+                <Self as ::binbuf::read::Read>::ENDIANNESS.verify_read::<E>()?;
+                #read_inner
+            }
+        }
+    }
+}
+
+/// This generates the ReadRef trait impl for structs with a lifetime
+/// parameter.
+pub fn gen_read_ref_impl(
+    struct_name: &Ident,
+    generics: &Generics,
+    lifetime: &Lifetime,
+    read_inner: TokenStream,
+    endianness: Endianness,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::binbuf::read::ReadRef<#lifetime> for #struct_name #ty_generics #where_clause {
+            fn read_ref<E: ::binbuf::Endianness>(buf: &mut ::binbuf::read::Reader<#lifetime>) -> ::binbuf::read::Result<Self> {
+                #endianness.verify_read::<E>()?;
+                #read_inner
+            }
+        }
+    }
+}
+
+/// This generates the TryRead trait impl for containers with a custom error
+/// type.
+pub fn gen_try_read_impl(
+    name: &Ident,
+    generics: &Generics,
+    read_inner: TokenStream,
+    error: &ExprPath,
+    endianness: Endianness,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::binbuf::read::TryRead for #name #ty_generics #where_clause {
+            type Error = #error;
+
+            fn try_read<E: ::binbuf::Endianness>(buf: &mut ::binbuf::read::Reader) -> ::std::result::Result<Self, Self::Error> {
+                #endianness.verify_read::<E>()?;
                 #read_inner
             }
         }
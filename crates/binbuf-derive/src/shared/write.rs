@@ -1,15 +1,79 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
+use syn::Generics;
 
-/// This generates a single write function call.
-pub fn gen_write_func(field_name: &Ident) -> TokenStream {
+use crate::attrs::{Endianness, FieldEncoding};
+
+/// This generates a single write function call. `value` is the expression
+/// used to access the field, like `self.field`. `endianness` is either the
+/// generic `E` or the byte order the field is pinned to.
+pub fn gen_multi_write_func(value: &TokenStream, endianness: &TokenStream) -> TokenStream {
     quote! {
-        self.#field_name.write::<E>(buf)
+        n += #value.write::<#endianness>(buf)?;
+    }
+}
+
+/// This generates a write function call for byte string fields which use the
+/// `cstring` or `padded` encoding. The field type has to implement
+/// `AsRef<[u8]>`.
+pub fn gen_write_encoded_func(
+    value: &TokenStream,
+    encoding: &FieldEncoding,
+    endianness: &TokenStream,
+) -> TokenStream {
+    match encoding {
+        FieldEncoding::CString { max_len, .. } => {
+            let max_len = match max_len {
+                Some(max_len) => quote! { Some(#max_len) },
+                None => quote! { None },
+            };
+
+            quote! {
+                n += buf.write_cstring(&#value, #max_len)?;
+            }
+        }
+        FieldEncoding::Padded { width, pad } => quote! {
+            n += buf.write_padded(&#value, #width, #pad)?;
+        },
+        FieldEncoding::Default => gen_multi_write_func(value, endianness),
     }
 }
 
-pub fn gen_multi_write_func(field_name: &Ident) -> TokenStream {
+/// This generates the Writeable trait impl.
+pub fn gen_writeable_impl(
+    name: &Ident,
+    generics: &Generics,
+    write_inner: TokenStream,
+    endianness: Endianness,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let doc_header = format!(" Write [`{name}`] to a [`WriteBuffer`].");
+    let doc_func = format!(
+        " {}.write::<BigEndian>(&mut buf).unwrap();",
+        name.to_string().to_lowercase(),
+    );
+
     quote! {
-        n += self.#field_name.write::<E>(buf)?;
+        #[automatically_derived]
+        impl #impl_generics ::binbuf::write::Write for #name #ty_generics #where_clause {
+            const ENDIANNESS: ::binbuf::SupportedEndianness = #endianness;
+
+            #[doc = #doc_header]
+            ///
+            /// ### Example
+            ///
+            /// ```
+            /// use binbuf::{write::WriteBuffer, BigEndian};
+            ///
+            /// let mut buf = WriteBuffer::new();
+            #[doc = #doc_func]
+            /// ```
+            fn write<E: ::binbuf::Endianness>(&self, buf: &mut ::binbuf::write::Writer) -> ::binbuf::write::Result
+            {
+                <Self as ::binbuf::write::Write>::ENDIANNESS.verify_write::<E>()?;
+                #write_inner
+            }
+        }
     }
 }
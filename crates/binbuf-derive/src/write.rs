@@ -1,96 +1,284 @@
-use proc_macro2::{Span, TokenStream};
-use quote::quote;
-use syn::{punctuated::Punctuated, token::Comma, DeriveInput, Error, Field, Result};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Error, ExprPath, Fields, Generics, Index,
+    Member, Result,
+};
 
 use crate::{
-    attrs::{AttrsParse, FieldAttrs, RawFieldAttrs},
-    shared,
+    attrs::{
+        AttrsParse, ByteOrder, Endianness, EnumWriteAttrs, FieldAttrs, RawContainerAttrs,
+        RawFieldAttrs, StructWriteAttrs,
+    },
+    shared::{self, EnumVariant},
 };
 
+/// Expand the `Writeable` derive macro by returning the generated
+/// [`TokenStream`].
 pub fn expand(input: DeriveInput) -> Result<TokenStream> {
-    let struct_name = &input.ident;
-
-    // First make sure we have a struct. Return the struct data
-    let struct_data = match shared::is_struct(input.data) {
-        Some(s) => s,
-        None => {
-            return Err(Error::new(
-                Span::call_site(),
-                "The Writeable derive macro can only be used with structs",
-            ))
-        }
+    match input.data {
+        Data::Struct(s) => expand_struct(s, &input.ident, input.attrs, &input.generics),
+        Data::Enum(e) => expand_enum(e, &input.ident, input.attrs, &input.generics),
+        Data::Union(_) => Err(Error::new(
+            Span::call_site(),
+            "The Writeable derive macro can only be used with structs or enums",
+        )),
+    }
+}
+
+fn expand_struct(
+    struct_data: DataStruct,
+    struct_name: &Ident,
+    struct_attrs: Vec<Attribute>,
+    generics: &Generics,
+) -> Result<TokenStream> {
+    let struct_attrs = RawContainerAttrs::parse::<StructWriteAttrs>(struct_attrs)?;
+    let write_inner = gen_multiple_fields(struct_data.fields, struct_attrs.byte_order)?;
+
+    let bound = quote! { ::binbuf::write::Write };
+    let generics = shared::add_trait_bounds(generics, bound, struct_attrs.bound.as_ref())?;
+
+    Ok(shared::gen_writeable_impl(
+        struct_name,
+        &generics,
+        write_inner,
+        struct_attrs.endianness,
+    ))
+}
+
+fn expand_enum(
+    enum_data: DataEnum,
+    enum_name: &Ident,
+    enum_attrs: Vec<Attribute>,
+    generics: &Generics,
+) -> Result<TokenStream> {
+    // If there are no variants, we don't generate code
+    if enum_data.variants.is_empty() {
+        return Ok(quote! {});
+    }
+
+    let enum_attrs = RawContainerAttrs::parse::<EnumWriteAttrs>(enum_attrs)?;
+    let repr: ExprPath = enum_attrs.repr.parse()?;
+    let repr_type = repr.path.get_ident().unwrap().to_string();
+
+    let variants = shared::extract_enum_variants(&enum_data, &repr_type)?;
+    shared::check_other_variant(&variants, enum_attrs.external_tag)?;
+
+    let bound = quote! { ::binbuf::write::Write };
+    let generics = shared::add_trait_bounds(generics, bound, enum_attrs.bound.as_ref())?;
+
+    // Enums with variants carrying data, or with an external tag, write the
+    // payload of the variant after the tag
+    if enum_attrs.external_tag || !shared::is_fieldless(&variants) {
+        return gen_write_payload_impl_enum(
+            enum_name,
+            &generics,
+            &variants,
+            &repr,
+            enum_attrs.external_tag,
+            enum_attrs.endianness,
+            enum_attrs.byte_order,
+        );
+    }
+
+    // The tag uses the byte order of the enum, if it is pinned
+    let tag_endianness = shared::gen_endianness(enum_attrs.byte_order);
+
+    let variants: Vec<TokenStream> = variants
+        .into_iter()
+        .map(|variant| match variant {
+            EnumVariant::Value(variant, variant_value) => {
+                let variant_ident = &variant.ident;
+                quote! {
+                    #enum_name::#variant_ident => #variant_value,
+                }
+            }
+            EnumVariant::Other(variant_ident) => quote! {
+                #enum_name::#variant_ident(value) => value,
+            },
+        })
+        .collect();
+
+    let write_inner = quote! {
+        let value: #repr = match *self {
+            #(#variants)*
+        };
+
+        ::binbuf::write::Write::write::<#tag_endianness>(&value, buf)
     };
 
-    // Extract all named fields. This will return an error if there are unnamed
-    // fields present
-    let named_fields = match shared::extract_named_fields_from_struct(struct_data) {
-        Some(f) => f.named,
-        None => {
-            return Err(Error::new(
-                Span::call_site(),
-                "The source struct only supports named fields",
-            ))
+    let writeable_impl =
+        shared::gen_writeable_impl(enum_name, &generics, write_inner, enum_attrs.endianness);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // Implement From<ENUM> for REPR, the counterpart of the TryFrom<REPR>
+    // impl generated by the Readable derive macro
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics From<#enum_name #ty_generics> for #repr #where_clause {
+            fn from(value: #enum_name #ty_generics) -> Self {
+                match value {
+                    #(#variants)*
+                }
+            }
         }
-    };
 
-    if named_fields.is_empty() {
-        return Ok(quote! {});
+        #writeable_impl
+    })
+}
+
+/// This generates the WritePayload trait impl. Unless the tag is supplied
+/// externally, the Write impl writes the tag first and then the payload.
+fn gen_write_payload_impl_enum(
+    enum_name: &Ident,
+    generics: &Generics,
+    variants: &[EnumVariant],
+    repr: &ExprPath,
+    external_tag: bool,
+    endianness: Endianness,
+    byte_order: Option<ByteOrder>,
+) -> Result<TokenStream> {
+    let tag_endianness = shared::gen_endianness(byte_order);
+
+    let mut tag_arms: Vec<TokenStream> = Vec::new();
+    let mut payload_arms: Vec<TokenStream> = Vec::new();
+
+    for variant in variants {
+        match variant {
+            EnumVariant::Value(variant, variant_value) => {
+                let variant_ident = &variant.ident;
+                let (pattern, write_fields) = gen_variant_fields(&variant.fields, byte_order)?;
+
+                tag_arms.push(quote! {
+                    Self::#variant_ident { .. } => #variant_value,
+                });
+                payload_arms.push(quote! {
+                    Self::#variant_ident #pattern => {
+                        #write_fields
+                    }
+                });
+            }
+            // The 'other' variant only holds the raw tag and has no payload
+            EnumVariant::Other(variant_ident) => {
+                tag_arms.push(quote! {
+                    Self::#variant_ident(value) => value,
+                });
+                payload_arms.push(quote! {
+                    Self::#variant_ident(_) => Ok(0),
+                });
+            }
+        }
     }
 
-    let c: TokenStream = if named_fields.len() == 1 {
-        match gen_one_field(named_fields.first().unwrap()) {
-            Ok(ts) => ts,
-            Err(err) => return Err(err),
+    let write_inner = if external_tag {
+        quote! {
+            ::binbuf::write::WritePayload::write_payload::<E>(self, buf)
         }
     } else {
-        match gen_multiple_fields(named_fields) {
-            Ok(ts) => ts,
-            Err(err) => return Err(err),
+        quote! {
+            let tag = ::binbuf::write::WritePayload::tag(self);
+            let n = ::binbuf::write::Write::write::<#tag_endianness>(&tag, buf)?;
+            Ok(n + ::binbuf::write::WritePayload::write_payload::<E>(self, buf)?)
         }
     };
 
-    let doc_header = format!(" Write [`{struct_name}`] to a [`WriteBuffer`].");
-    let doc_func = format!(
-        " {}.write::<BigEndian>(&mut buf).unwrap();",
-        struct_name.to_string().to_lowercase(),
-    );
+    let writeable_impl = shared::gen_writeable_impl(enum_name, generics, write_inner, endianness);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     Ok(quote! {
         #[automatically_derived]
-        impl ::binbuf::write::Write for #struct_name {
-            #[doc = #doc_header]
-            ///
-            /// ### Example
-            ///
-            /// ```
-            /// use binbuf::{write::WriteBuffer, BigEndian};
-            ///
-            /// let mut buf = WriteBuffer::new();
-            #[doc = #doc_func]
-            /// ```
-            fn write<E: ::binbuf::Endianness>(&self, buf: &mut ::binbuf::write::Writer) -> ::binbuf::write::Result
-            {
-                #c
+        impl #impl_generics ::binbuf::write::WritePayload for #enum_name #ty_generics #where_clause {
+            type Tag = #repr;
+
+            fn tag(&self) -> #repr {
+                match *self {
+                    #(#tag_arms)*
+                }
+            }
+
+            fn write_payload<E: ::binbuf::Endianness>(
+                &self,
+                buf: &mut ::binbuf::write::Writer,
+            ) -> ::binbuf::write::Result {
+                #endianness.verify_write::<E>()?;
+
+                match self {
+                    #(#payload_arms)*
+                }
             }
         }
+
+        #writeable_impl
     })
 }
 
-fn gen_one_field(field: &Field) -> Result<TokenStream> {
-    // Extract the field name
-    let field_name = field.ident.as_ref().unwrap();
-    let func = shared::gen_write_func(field_name);
+fn gen_multiple_fields(fields: Fields, byte_order: Option<ByteOrder>) -> Result<TokenStream> {
+    let mut values = Vec::new();
 
-    Ok(quote! {
-        #func
-    })
+    for (index, field) in fields.iter().enumerate() {
+        // Tuple struct fields are accessed by their index
+        let member = match &field.ident {
+            Some(field_name) => Member::Named(field_name.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+
+        values.push(quote! { self.#member });
+    }
+
+    gen_fields(&fields, values, byte_order)
+}
+
+/// This generates the pattern binding the fields of an enum variant and the
+/// code to write them. Skipped fields are not bound.
+fn gen_variant_fields(
+    fields: &Fields,
+    byte_order: Option<ByteOrder>,
+) -> Result<(TokenStream, TokenStream)> {
+    let mut bindings = Vec::new();
+    let mut values = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let attrs = RawFieldAttrs::parse::<FieldAttrs>(field.attrs.clone())?;
+
+        let var_name = match &field.ident {
+            Some(field_name) => format_ident!("_gen_{}", field_name),
+            None => format_ident!("_gen_{}", index),
+        };
+
+        let binding = if attrs.skip_write.value {
+            quote! { _ }
+        } else {
+            quote! { #var_name }
+        };
+
+        bindings.push(match &field.ident {
+            Some(field_name) => quote! { #field_name: #binding, },
+            None => quote! { #binding, },
+        });
+        values.push(quote! { (*#var_name) });
+    }
+
+    let pattern = match fields {
+        Fields::Named(_) => quote! { { #(#bindings)* } },
+        Fields::Unnamed(_) => quote! { ( #(#bindings)* ) },
+        Fields::Unit => quote! {},
+    };
+
+    Ok((pattern, gen_fields(fields, values, byte_order)?))
 }
 
-fn gen_multiple_fields(fields: Punctuated<Field, Comma>) -> Result<TokenStream> {
+/// This generates the code to write `fields`. `values` contains the
+/// expression used to access each field. Fields which are not pinned to a
+/// byte order use the container's `byte_order`, if any.
+fn gen_fields(
+    fields: &Fields,
+    values: Vec<TokenStream>,
+    byte_order: Option<ByteOrder>,
+) -> Result<TokenStream> {
     // Prepare the individual parts of the code gen
     let mut funcs: Vec<TokenStream> = Vec::new();
 
-    for field in fields {
+    for (field, value) in fields.iter().zip(values) {
         // Extract field attrs
         let attrs = RawFieldAttrs::parse::<FieldAttrs>(field.attrs.clone())?;
 
@@ -98,8 +286,17 @@ fn gen_multiple_fields(fields: Punctuated<Field, Comma>) -> Result<TokenStream>
             continue;
         }
 
-        let field_name = field.ident.as_ref().unwrap();
-        funcs.push(shared::gen_multi_write_func(field_name));
+        let endianness = shared::gen_endianness(attrs.byte_order.or(byte_order));
+        funcs.push(shared::gen_write_encoded_func(
+            &value,
+            &attrs.encoding,
+            &endianness,
+        ));
+    }
+
+    // Unit structs and structs with only skipped fields write nothing
+    if funcs.is_empty() {
+        return Ok(quote! { Ok(0) });
     }
 
     Ok(quote! {
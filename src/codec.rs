@@ -0,0 +1,285 @@
+//! A [`tokio_util::codec`] bridge for types implementing [`Read`] and
+//! [`Write`]. This module requires the `codec` feature.
+//!
+//! [`Codec`] frames each value with a length prefix of type `L`, which can
+//! be any unsigned integer, like `u8`, `u16` or `u32`. The value is encoded
+//! with the endianness `E` and the prefix with the endianness `P`, which
+//! defaults to `E`. Combined with `tokio_util::codec::Framed`, this turns any
+//! byte stream into a stream of typed messages.
+//!
+//! ### Example
+//!
+//! ```
+//! use binbuf::{codec::Codec, BigEndian, LittleEndian};
+//! use tokio_util::{
+//!     bytes::BytesMut,
+//!     codec::{Decoder, Encoder},
+//! };
+//!
+//! let mut codec = Codec::<u32, u16, BigEndian>::new();
+//! let mut buf = BytesMut::new();
+//!
+//! codec.encode(1163411789, &mut buf).unwrap();
+//! assert_eq!(&buf[..], &[0, 4, 69, 88, 65, 77]);
+//!
+//! assert_eq!(codec.decode(&mut buf), Ok(Some(1163411789)));
+//! assert!(buf.is_empty());
+//!
+//! // A little endian prefix followed by a big endian value
+//! let mut codec = Codec::<u32, u16, BigEndian, LittleEndian>::new();
+//!
+//! codec.encode(1163411789, &mut buf).unwrap();
+//! assert_eq!(&buf[..], &[4, 0, 69, 88, 65, 77]);
+//! ```
+
+use std::{fmt, io, marker::PhantomData};
+
+use snafu::{ensure, Snafu};
+use tokio_util::{
+    bytes::{Buf, BytesMut},
+    codec::{Decoder, Encoder},
+};
+
+use crate::{
+    read,
+    write::{FloatPolicy, WriteError},
+    BigEndian, Endianness, Read, Reader, Write, Writer,
+};
+
+/// The default maximum length of a frame body, 8 MiB.
+const DEFAULT_MAX_FRAME_LEN: usize = 8 * 1024 * 1024;
+
+pub type Result<T, E = CodecError> = std::result::Result<T, E>;
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum CodecError {
+    #[snafu(display("failed to decode frame: {source}"))]
+    Decode { source: read::Error },
+
+    #[snafu(display("failed to encode frame: {source}"))]
+    Encode { source: WriteError },
+
+    /// This error indicates that the length prefix of a frame exceeds the
+    /// configured maximum frame length.
+    #[snafu(display("frame length {len} exceeds the maximum of {max} bytes"))]
+    FrameTooLong { len: usize, max: usize },
+
+    /// This error indicates that the value of a frame didn't consume the
+    /// whole frame body.
+    #[snafu(display("frame contains {len} trailing bytes"))]
+    TrailingBytes { len: usize },
+
+    /// This error indicates that reading from or writing to the underlying
+    /// transport failed.
+    #[snafu(display("i/o error: {message}"))]
+    Io {
+        kind: io::ErrorKind,
+        message: String,
+    },
+}
+
+impl From<read::Error> for CodecError {
+    fn from(source: read::Error) -> Self {
+        Self::Decode { source }
+    }
+}
+
+impl From<WriteError> for CodecError {
+    fn from(source: WriteError) -> Self {
+        Self::Encode { source }
+    }
+}
+
+impl From<io::Error> for CodecError {
+    fn from(err: io::Error) -> Self {
+        IoSnafu {
+            kind: err.kind(),
+            message: err.to_string(),
+        }
+        .build()
+    }
+}
+
+/// A length-prefixed codec which decodes and encodes values of type `T`.
+/// Each frame consists of a length prefix of type `L`, followed by the
+/// encoded value. The value is encoded with the endianness `E`, the prefix
+/// with the endianness `P`. By default, both use the same endianness.
+///
+/// Incomplete frames are buffered until enough bytes are available. Frames
+/// which are longer than the maximum frame length (8 MiB by default) are
+/// rejected with [`CodecError::FrameTooLong`].
+pub struct Codec<T, L = u16, E = BigEndian, P = E> {
+    max_frame_len: usize,
+    floats: FloatPolicy,
+    _marker: PhantomData<(T, L, E, P)>,
+}
+
+impl<T, L, E, P> fmt::Debug for Codec<T, L, E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Codec")
+            .field("max_frame_len", &self.max_frame_len)
+            .field("floats", &self.floats)
+            .finish()
+    }
+}
+
+impl<T, L, E, P> Clone for Codec<T, L, E, P> {
+    fn clone(&self) -> Self {
+        Self {
+            max_frame_len: self.max_frame_len,
+            floats: self.floats,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, L, E, P> Default for Codec<T, L, E, P> {
+    fn default() -> Self {
+        Self {
+            max_frame_len: DEFAULT_MAX_FRAME_LEN,
+            floats: FloatPolicy::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, L, E, P> Codec<T, L, E, P> {
+    /// Create a new [`Codec`] with the default maximum frame length.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum length of a frame body in bytes. The length prefix
+    /// is not included.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::codec::{Codec, CodecError};
+    /// use tokio_util::{bytes::BytesMut, codec::Decoder};
+    ///
+    /// let mut codec = Codec::<u32, u8>::new().with_max_frame_len(2);
+    /// let mut buf = BytesMut::from(&[4, 69, 88, 65, 77][..]);
+    ///
+    /// assert_eq!(
+    ///     codec.decode(&mut buf),
+    ///     Err(CodecError::FrameTooLong { len: 4, max: 2 })
+    /// );
+    /// ```
+    pub fn with_max_frame_len(mut self, max_frame_len: usize) -> Self {
+        self.max_frame_len = max_frame_len;
+        self
+    }
+
+    /// Returns the maximum length of a frame body in bytes.
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    /// Sets the [`FloatPolicy`] used when encoding `f32` and `f64` values.
+    /// The default policy is [`FloatPolicy::Preserve`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{codec::Codec, write::FloatPolicy};
+    /// use tokio_util::{bytes::BytesMut, codec::Encoder};
+    ///
+    /// let mut codec = Codec::<f32, u8>::new().with_float_policy(FloatPolicy::Canonicalize);
+    /// let mut buf = BytesMut::new();
+    ///
+    /// codec.encode(-0.0, &mut buf).unwrap();
+    /// assert_eq!(&buf[..], &[4, 0, 0, 0, 0]);
+    /// ```
+    pub fn with_float_policy(mut self, policy: FloatPolicy) -> Self {
+        self.floats = policy;
+        self
+    }
+
+    /// Returns the [`FloatPolicy`] used when encoding `f32` and `f64` values.
+    pub fn float_policy(&self) -> FloatPolicy {
+        self.floats
+    }
+}
+
+impl<T, L, E, P> Decoder for Codec<T, L, E, P>
+where
+    T: Read,
+    L: Read,
+    usize: TryFrom<L>,
+    E: Endianness,
+    P: Endianness,
+{
+    type Item = T;
+    type Error = CodecError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<T>> {
+        let (len, offset) = {
+            let mut reader = Reader::new(src);
+
+            match L::read::<P>(&mut reader) {
+                Ok(len) => (len, reader.offset()),
+                Err(read::Error::BufferTooShort) => return Ok(None),
+                Err(err) => return Err(err.into()),
+            }
+        };
+
+        let len = usize::try_from(len).unwrap_or(usize::MAX);
+
+        ensure!(
+            len <= self.max_frame_len,
+            FrameTooLongSnafu {
+                len,
+                max: self.max_frame_len
+            }
+        );
+
+        if src.len() - offset < len {
+            src.reserve(offset + len - src.len());
+            return Ok(None);
+        }
+
+        // The frame is complete at this point, running out of bytes while
+        // reading the value indicates a malformed frame
+        let value = {
+            let mut reader = Reader::new(&src[offset..offset + len]);
+            let value = T::read::<E>(&mut reader)?;
+
+            ensure!(reader.is_empty(), TrailingBytesSnafu { len: reader.len() });
+            value
+        };
+
+        src.advance(offset + len);
+        Ok(Some(value))
+    }
+}
+
+impl<T, L, E, P> Encoder<T> for Codec<T, L, E, P>
+where
+    T: Write,
+    L: Write + Default + TryFrom<usize>,
+    E: Endianness,
+    P: Endianness,
+{
+    type Error = CodecError;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<()> {
+        let mut buf = Writer::new();
+        buf.set_float_policy(self.floats);
+
+        let slot = buf.reserve_length::<L, P>()?;
+        item.write::<E>(&mut buf)?;
+        let len = buf.patch_length(slot)?;
+
+        ensure!(
+            len <= self.max_frame_len,
+            FrameTooLongSnafu {
+                len,
+                max: self.max_frame_len
+            }
+        );
+
+        dst.extend_from_slice(buf.bytes());
+        Ok(())
+    }
+}
@@ -1,8 +1,13 @@
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::{
+    borrow::Cow,
+    net::{Ipv4Addr, Ipv6Addr},
+};
 
 use crate::{
-    read::{self, Read, Reader},
-    write::{self, Write, WriteError, Writer},
+    read::{self, InvalidUtf8Snafu, Read, ReadRef, Reader},
+    string::StringPrefix,
+    varint::Uleb128,
+    write::{self, Write, Writer},
     Endianness,
 };
 
@@ -21,13 +26,13 @@ impl Read for Ipv4Addr {
 impl Write for Ipv4Addr {
     fn write_be(&self, buf: &mut Writer) -> write::Result<usize> {
         let b = self.octets();
-        Ok(buf.write(b))
+        buf.try_write(b)
     }
 
     fn write_le(&self, buf: &mut Writer) -> write::Result<usize> {
         let mut b = self.octets();
         b.reverse();
-        Ok(buf.write(b))
+        buf.try_write(b)
     }
 }
 
@@ -46,22 +51,144 @@ impl Read for Ipv6Addr {
 impl Write for Ipv6Addr {
     fn write_be(&self, buf: &mut Writer) -> write::Result<usize> {
         let b = self.octets();
-        Ok(buf.write(b))
+        buf.try_write(b)
     }
 
     fn write_le(&self, buf: &mut Writer) -> write::Result<usize> {
         let mut b = self.octets();
         b.reverse();
-        Ok(buf.write(b))
+        buf.try_write(b)
+    }
+}
+
+impl Read for String {
+    fn read<E: Endianness>(buf: &mut Reader) -> read::Result<Self> {
+        let policy = buf.string_policy();
+
+        let s = match buf.string_prefix() {
+            StringPrefix::None => {
+                let len = buf.fill_to_end()?;
+                buf.read_str(len, policy)?
+            }
+            StringPrefix::U8 => buf.read_str_prefixed::<u8, E>(None, policy)?,
+            StringPrefix::U16 => buf.read_str_prefixed::<u16, E>(None, policy)?,
+            StringPrefix::U32 => buf.read_str_prefixed::<u32, E>(None, policy)?,
+            StringPrefix::Uleb128 => buf.read_str_prefixed::<Uleb128, E>(None, policy)?,
+        };
+
+        Ok(s.into_owned())
+    }
+}
+
+impl<'a> ReadRef<'a> for &'a [u8] {
+    fn read_ref<E: Endianness>(buf: &mut Reader<'a>) -> read::Result<Self> {
+        // Fail before the prefix is consumed, the bytes can't be borrowed
+        // from streaming readers anyway
+        if buf.is_streaming() {
+            return Err(read::Error::BorrowUnsupported);
+        }
+
+        let start = buf.offset();
+        let result = read_string_len::<E>(buf).and_then(|len| buf.read_slice_ref(len));
+
+        if result.is_err() {
+            buf.rewind(start)?;
+        }
+
+        result
+    }
+}
+
+impl<'a> ReadRef<'a> for &'a str {
+    fn read_ref<E: Endianness>(buf: &mut Reader<'a>) -> read::Result<Self> {
+        let start = buf.offset();
+        let bytes = <&[u8]>::read_ref::<E>(buf)?;
+        let offset = buf.offset() - bytes.len();
+
+        // Borrowed strings can't replace invalid sequences, which is why
+        // lossy reads are as strict as UTF-8 reads
+        let result = buf.string_policy().check(bytes, offset).and_then(|_| {
+            std::str::from_utf8(bytes).map_err(|err| {
+                InvalidUtf8Snafu {
+                    offset: offset + err.valid_up_to(),
+                }
+                .build()
+            })
+        });
+
+        if result.is_err() {
+            buf.rewind(start)?;
+        }
+
+        result
+    }
+}
+
+impl<'a> ReadRef<'a> for Cow<'a, str> {
+    fn read_ref<E: Endianness>(buf: &mut Reader<'a>) -> read::Result<Self> {
+        let start = buf.offset();
+        let bytes = <&[u8]>::read_ref::<E>(buf)?;
+        let offset = buf.offset() - bytes.len();
+
+        let policy = buf.string_policy();
+        if let Err(err) = policy.check(bytes, offset) {
+            buf.rewind(start)?;
+            return Err(err);
+        }
+
+        Ok(policy.decode(bytes))
+    }
+}
+
+/// Reads the length prefix selected by the [`StringPrefix`] of the reader.
+/// Without a prefix, the length of the remaining input is returned.
+fn read_string_len<E: Endianness>(buf: &mut Reader) -> read::Result<usize> {
+    fn prefix<L, E>(buf: &mut Reader) -> read::Result<usize>
+    where
+        L: Read,
+        usize: TryFrom<L>,
+        E: Endianness,
+    {
+        usize::try_from(L::read::<E>(buf)?).map_err(|_| read::Error::MaxLengthOverflow)
+    }
+
+    match buf.string_prefix() {
+        StringPrefix::None => buf.fill_to_end(),
+        StringPrefix::U8 => prefix::<u8, E>(buf),
+        StringPrefix::U16 => prefix::<u16, E>(buf),
+        StringPrefix::U32 => prefix::<u32, E>(buf),
+        StringPrefix::Uleb128 => prefix::<Uleb128, E>(buf),
     }
 }
 
 impl Write for String {
     fn write<E: Endianness>(&self, buf: &mut Writer) -> write::Result {
-        if !self.is_ascii() {
-            return Err(WriteError::NonAsciiData);
+        self.as_str().write::<E>(buf)
+    }
+}
+
+impl Write for &[u8] {
+    fn write<E: Endianness>(&self, buf: &mut Writer) -> write::Result {
+        match buf.string_prefix() {
+            StringPrefix::None => buf.try_write(self),
+            StringPrefix::U8 => buf.write_length_prefixed::<u8, E>(self, None),
+            StringPrefix::U16 => buf.write_length_prefixed::<u16, E>(self, None),
+            StringPrefix::U32 => buf.write_length_prefixed::<u32, E>(self, None),
+            StringPrefix::Uleb128 => buf.write_length_prefixed::<Uleb128, E>(self, None),
         }
+    }
+}
 
-        Ok(buf.write(self.as_bytes()))
+impl Write for &str {
+    fn write<E: Endianness>(&self, buf: &mut Writer) -> write::Result {
+        let policy = buf.string_policy();
+
+        match buf.string_prefix() {
+            StringPrefix::None => buf.write_str(self, policy),
+            StringPrefix::U8 => buf.write_str_prefixed::<u8, E>(self, None, policy),
+            StringPrefix::U16 => buf.write_str_prefixed::<u16, E>(self, None, policy),
+            StringPrefix::U32 => buf.write_str_prefixed::<u32, E>(self, None, policy),
+            StringPrefix::Uleb128 => buf.write_str_prefixed::<Uleb128, E>(self, None, policy),
+        }
     }
 }
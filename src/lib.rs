@@ -1,8 +1,8 @@
 //! A small yet powerfull library to work with binary (network) data in Rust.
 //! The library makes the proccess of writing and reading data of network
 //! protocols easy. It has out-of-the-box support for various `std` types,
-//! like all unsigned integers as well as `Ipv4Addr` and `Ipv6Addr` in big and
-//! little endian byte order.
+//! like all signed and unsigned integers as well as `Ipv4Addr` and `Ipv6Addr`
+//! in big and little endian byte order.
 //!
 //! # A Tour of binbuf
 //!
@@ -37,16 +37,131 @@ mod impls;
 mod macros;
 
 pub mod read;
+pub mod string;
+pub mod varint;
 pub mod write;
 
+#[cfg(feature = "codec")]
+pub mod codec;
+
+#[cfg(feature = "tokio")]
+pub mod tokio;
+
 pub use crate::{
-    read::{Read, ReadableMulti, Reader},
-    write::{Write, Writer},
+    read::{BitReader, Read, ReadPayload, ReadRef, ReadableMulti, Reader, TryRead},
+    write::{BitWriter, Write, WritePayload, Writer},
 };
 
+/// The order in which individual bits are read from or written to a byte
+/// when working with packed bit fields.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    /// The most significant bit of each byte comes first. Values are assembled
+    /// with the first bit being the most significant one. This is the order
+    /// used by most network protocol headers, like IPv4, TCP or DNS.
+    #[default]
+    MsbFirst,
+
+    /// The least significant bit of each byte comes first. Values are
+    /// assembled with the first bit being the least significant one. This is
+    /// the order used by DEFLATE for example.
+    LsbFirst,
+}
+
+/// The byte order of an [`Endianness`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// The most significant byte comes first.
+    Big,
+
+    /// The least significant byte comes first.
+    Little,
+}
+
+/// The byte orders supported by a type. Types declare it using
+/// [`Read::ENDIANNESS`] and [`Write::ENDIANNESS`]. Derived types use the
+/// `endianness` container attribute.
+///
+/// ### Example
+///
+/// ```
+/// use binbuf::{read, BigEndian, LittleEndian, SupportedEndianness};
+///
+/// let supported = SupportedEndianness::Big;
+///
+/// assert_eq!(supported.verify_read::<BigEndian>(), Ok(()));
+/// assert_eq!(
+///     supported.verify_read::<LittleEndian>(),
+///     Err(read::Error::LittleEndianNotSupported)
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SupportedEndianness {
+    /// Only big endian is supported.
+    Big,
+
+    /// Only little endian is supported.
+    Little,
+
+    /// Both byte orders are supported.
+    #[default]
+    Both,
+}
+
+impl SupportedEndianness {
+    /// Returns if the byte `order` is supported.
+    pub fn supports(self, order: ByteOrder) -> bool {
+        matches!(
+            (self, order),
+            (Self::Both, _) | (Self::Big, ByteOrder::Big) | (Self::Little, ByteOrder::Little)
+        )
+    }
+
+    /// Returns an error if reading with the endianness `E` is not supported.
+    pub fn verify_read<E: Endianness>(self) -> read::Result<()> {
+        let order = E::order();
+
+        match (self.supports(order), order) {
+            (true, _) => Ok(()),
+            (false, ByteOrder::Big) => Err(read::Error::BigEndianNotSupported),
+            (false, ByteOrder::Little) => Err(read::Error::LittleEndianNotSupported),
+        }
+    }
+
+    /// Returns an error if writing with the endianness `E` is not supported.
+    pub fn verify_write<E: Endianness>(self) -> write::Result<()> {
+        let order = E::order();
+
+        match (self.supports(order), order) {
+            (true, _) => Ok(()),
+            (false, ByteOrder::Big) => Err(write::WriteError::BigEndianNotSupported),
+            (false, ByteOrder::Little) => Err(write::WriteError::LittleEndianNotSupported),
+        }
+    }
+}
+
 pub trait Endianness {
     fn read<T: Read>(buf: &mut Reader) -> read::Result<T>;
     fn write<T: Write>(n: &T, buf: &mut Writer) -> write::Result;
+
+    /// Returns the byte order values are read and written with.
+    /// Implementations should override this method.
+    ///
+    /// The default implementation only exists so that implementations which
+    /// predate this method keep compiling. It reads the bytes `[0, 1]` as a
+    /// `u16` using [`Endianness::read`].
+    ///
+    /// ### Panics
+    ///
+    /// The default implementation panics if the `u16` is decoded neither in
+    /// big nor in little endian byte order.
+    fn order() -> ByteOrder {
+        match Self::read::<u16>(&mut Reader::new(&[0, 1])) {
+            Ok(1) => ByteOrder::Big,
+            Ok(256) => ByteOrder::Little,
+            result => panic!("cannot determine the byte order, [0, 1] was read as {result:?}"),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -59,6 +174,10 @@ impl Endianness for BigEndian {
     fn write<T: Write>(n: &T, buf: &mut Writer) -> write::Result {
         n.write_be(buf)
     }
+
+    fn order() -> ByteOrder {
+        ByteOrder::Big
+    }
 }
 
 #[derive(Debug)]
@@ -71,6 +190,10 @@ impl Endianness for LittleEndian {
     fn write<T: Write>(n: &T, buf: &mut Writer) -> write::Result {
         n.write_le(buf)
     }
+
+    fn order() -> ByteOrder {
+        ByteOrder::Little
+    }
 }
 
 #[cfg(feature = "derive")]
@@ -21,12 +21,28 @@ macro_rules! into_buffer_and_writeable_impl {
         impl Write for $SelfT {
             fn write_be(&self, buf: &mut $crate::write::Writer) -> crate::write::Result {
                 let b = self.to_be_bytes();
-                Ok(buf.write(b))
+                buf.try_write(b)
             }
 
             fn write_le(&self, buf: &mut $crate::write::Writer) -> crate::write::Result {
                 let b = self.to_le_bytes();
-                Ok(buf.write(b))
+                buf.try_write(b)
+            }
+        }
+    };
+}
+
+macro_rules! into_buffer_and_writeable_float_impl {
+    ($SelfT:ty) => {
+        impl Write for $SelfT {
+            fn write_be(&self, buf: &mut $crate::write::Writer) -> crate::write::Result {
+                let b = buf.float_policy().apply(*self)?.to_be_bytes();
+                buf.try_write(b)
+            }
+
+            fn write_le(&self, buf: &mut $crate::write::Writer) -> crate::write::Result {
+                let b = buf.float_policy().apply(*self)?.to_le_bytes();
+                buf.try_write(b)
             }
         }
     };
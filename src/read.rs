@@ -1,9 +1,24 @@
+use std::{borrow::Cow, fmt, io};
+
 use snafu::{ensure, OptionExt, Snafu};
 
-use crate::{BigEndian, Endianness, LittleEndian};
+use crate::{
+    string::{StringPolicy, StringPrefix},
+    BigEndian, BitOrder, Endianness, LittleEndian, SupportedEndianness,
+};
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
+/// The default capacity of the refill buffer of streaming readers.
+const DEFAULT_STREAM_CAPACITY: usize = 8 * 1024;
+
+/// The default maximum length of length-prefixed byte strings, 8 MiB.
+pub(crate) const DEFAULT_LENGTH_LIMIT: usize = 8 * 1024 * 1024;
+
+/// The maximum length of a domain name in wire format, including all length
+/// octets and the terminating null label (RFC 1035, section 2.3.4).
+const MAX_DOMAIN_NAME_LEN: usize = 255;
+
 #[derive(Debug, PartialEq, Snafu)]
 pub enum Error {
     /// This error indicates that the buffer is too short to read the
@@ -28,15 +43,195 @@ pub enum Error {
     #[snafu(display("invalid data"))]
     InvalidData,
 
+    /// This error indicates that the buffer is too short to read the
+    /// requested amount of bits.
+    #[snafu(display("buffer too short to read {nbits} bits at bit offset {offset}"))]
+    BitBufferTooShort {
+        nbits: u32,
+        offset: usize,
+    },
+
+    #[snafu(display(
+        "cannot read {nbits} bits at bit offset {offset}, at most 64 bits are supported"
+    ))]
+    BitCountOverflow {
+        nbits: u32,
+        offset: usize,
+    },
+
+    #[snafu(display("compression pointer loop detected, offset {index} was already visited"))]
+    PointerLoop {
+        index: usize,
+    },
+
+    #[snafu(display("domain name exceeds the maximum length of 255 bytes"))]
+    NameTooLong,
+
+    #[snafu(display("invalid label type {byte:#04x} at offset {offset}"))]
+    InvalidLabelType {
+        byte: u8,
+        offset: usize,
+    },
+
+    /// This error indicates that reading from the underlying source of a
+    /// streaming reader failed.
+    #[snafu(display("i/o error: {message}"))]
+    Io {
+        kind: io::ErrorKind,
+        message: String,
+    },
+
+    #[snafu(display("jumping is not supported by streaming readers"))]
+    JumpUnsupported,
+
+    /// This error indicates that a value borrowing from the input was read
+    /// from a streaming reader, see [`ReadRef`].
+    #[snafu(display("borrowing from the input is not supported by streaming readers"))]
+    BorrowUnsupported,
+
+    /// This error indicates that a streaming reader cannot move the cursor
+    /// back to `offset`, because the bytes were already discarded from the
+    /// refill buffer.
+    #[snafu(display("cannot rewind to offset {offset}, the bytes were already discarded"))]
+    RewindUnsupported {
+        offset: usize,
+    },
+
+    /// This error indicates that a variable-length integer encodes a value
+    /// which doesn't fit into the target type.
+    #[snafu(visibility(pub(crate)))]
+    #[snafu(display("variable-length integer overflows {bits} bits"))]
+    VarIntOverflow {
+        bits: u32,
+    },
+
+    /// This error indicates that a variable-length integer is not encoded
+    /// using the minimal number of bytes.
+    #[snafu(visibility(pub(crate)))]
+    #[snafu(display("overlong variable-length integer encoding"))]
+    OverlongVarInt,
+
+    /// This error indicates that a string read with
+    /// [`StringPolicy::Ascii`] contains a non-ASCII byte at `offset`.
+    #[snafu(visibility(pub(crate)))]
+    #[snafu(display("non-ascii string data at offset {offset}"))]
+    NonAsciiData {
+        offset: usize,
+    },
+
+    /// This error indicates that a string read with [`StringPolicy::Utf8`]
+    /// contains an invalid UTF-8 sequence starting at `offset`.
+    #[snafu(visibility(pub(crate)))]
+    #[snafu(display("invalid utf-8 string data at offset {offset}"))]
+    InvalidUtf8 {
+        offset: usize,
+    },
+
     LittleEndianNotSupported,
     BigEndianNotSupported,
 }
 
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        IoSnafu {
+            kind: err.kind(),
+            message: err.to_string(),
+        }
+        .build()
+    }
+}
+
+/// Controls how a missing terminator is handled when reading NUL-terminated
+/// strings using [`Reader::read_cstring()`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Terminator {
+    /// The string must be terminated by a NUL byte.
+    #[default]
+    Required,
+
+    /// The string ends at the first NUL byte, after the maximum length or at
+    /// the end of the buffer, whichever comes first.
+    Optional,
+}
+
 #[derive(Debug)]
 pub struct Reader<'a> {
     jump_indices: Vec<usize>,
     buf: &'a [u8],
     rest: &'a [u8],
+    stream: Option<Stream<'a>>,
+    strings: StringPolicy,
+    string_prefix: StringPrefix,
+    length_limit: usize,
+}
+
+/// The state of a streaming [`Reader`]. Bytes are read from `inner` into
+/// `buf` on demand. Consumed bytes are discarded from the front of `buf`
+/// before the buffer is refilled, once they exceed `capacity` bytes.
+struct Stream<'a> {
+    inner: Box<dyn io::Read + 'a>,
+    buf: Vec<u8>,
+
+    /// The position of the cursor in `buf`.
+    pos: usize,
+
+    /// The number of bytes discarded from the front of `buf`.
+    discarded: usize,
+
+    /// The minimum number of bytes requested from `inner` per refill.
+    capacity: usize,
+}
+
+impl fmt::Debug for Stream<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stream")
+            .field("buffered", &(self.buf.len() - self.pos))
+            .field("pos", &self.pos)
+            .field("discarded", &self.discarded)
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Stream<'_> {
+    /// Reads from the underlying source until at least `nbytes` bytes are
+    /// buffered after the cursor. The buffer grows by at most `capacity`
+    /// bytes per read, so that a large `nbytes` doesn't allocate memory for
+    /// bytes the source never delivers.
+    fn fill(&mut self, nbytes: usize) -> Result<()> {
+        if self.buf.len() - self.pos >= nbytes {
+            return Ok(());
+        }
+
+        // Discard consumed bytes before reading more data. Up to `capacity`
+        // consumed bytes are kept, so that the cursor can be moved back if
+        // reading a value fails
+        if self.pos >= self.capacity {
+            self.buf.drain(..self.pos);
+            self.discarded += self.pos;
+            self.pos = 0;
+        }
+
+        while self.buf.len() - self.pos < nbytes {
+            let len = self.buf.len();
+            self.buf.resize(len + self.capacity, 0);
+
+            match self.inner.read(&mut self.buf[len..]) {
+                Ok(0) => {
+                    self.buf.truncate(len);
+                    return BufferTooShortSnafu.fail();
+                }
+                Ok(n) => self.buf.truncate(len + n),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => self.buf.truncate(len),
+                Err(err) => {
+                    self.buf.truncate(len);
+                    return Err(err.into());
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> Reader<'a> {
@@ -56,6 +251,96 @@ impl<'a> Reader<'a> {
             buf,
             rest: buf,
             jump_indices: Vec::new(),
+            stream: None,
+            strings: StringPolicy::default(),
+            string_prefix: StringPrefix::default(),
+            length_limit: DEFAULT_LENGTH_LIMIT,
+        }
+    }
+
+    /// Create a new streaming [`Reader`] which reads from any
+    /// [`std::io::Read`], like a `TcpStream` or a `File`. Bytes are read into
+    /// an internal refill buffer on demand, so the whole input doesn't need
+    /// to be buffered upfront. All [`Read`] implementations work with
+    /// streaming readers. Errors of the underlying source are returned as
+    /// [`Error::Io`]. Reaching the end of the source while reading returns
+    /// [`Error::BufferTooShort`].
+    ///
+    /// Streaming readers differ from slice-based readers in a few ways:
+    ///
+    /// - [`Reader::len()`], [`Reader::is_empty()`], [`Reader::peek()`] and
+    ///   [`Reader::peekn()`] only take already buffered bytes into account.
+    ///   Use [`Reader::fill()`] to buffer bytes before peeking.
+    /// - Jumping is not supported. [`Reader::jump_to()`] returns
+    ///   [`Error::JumpUnsupported`] and [`Reader::reset()`] is a no-op. This
+    ///   also means that compressed domain names cannot be read.
+    /// - Values which fail to read move the cursor back to their start. Only
+    ///   up to `capacity` consumed bytes are kept in the refill buffer. If the
+    ///   start was already discarded, [`Error::RewindUnsupported`] is returned
+    ///   instead and the position in the stream is lost.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{BigEndian, Read as _, Reader};
+    ///
+    /// let d: &[u8] = &[69, 88, 65, 77, 80, 76, 69, 33];
+    /// let mut b = Reader::from_reader(d);
+    ///
+    /// assert_eq!(u16::read::<BigEndian>(&mut b), Ok(17752));
+    /// assert_eq!(u32::read::<BigEndian>(&mut b), Ok(1095585868));
+    /// assert_eq!(b.offset(), 6);
+    /// ```
+    pub fn from_reader(inner: impl io::Read + 'a) -> Self {
+        Self::from_reader_with_capacity(DEFAULT_STREAM_CAPACITY, inner)
+    }
+
+    /// Create a new streaming [`Reader`] which reads at least `capacity`
+    /// bytes from `inner` per refill. See [`Reader::from_reader()`] for more
+    /// details.
+    pub fn from_reader_with_capacity(capacity: usize, inner: impl io::Read + 'a) -> Self {
+        Reader {
+            buf: &[],
+            rest: &[],
+            jump_indices: Vec::new(),
+            stream: Some(Stream {
+                inner: Box::new(inner),
+                buf: Vec::new(),
+                pos: 0,
+                discarded: 0,
+                capacity: capacity.max(1),
+            }),
+            strings: StringPolicy::default(),
+            string_prefix: StringPrefix::default(),
+            length_limit: DEFAULT_LENGTH_LIMIT,
+        }
+    }
+
+    /// Ensure that at least `nbytes` bytes can be read without reaching the
+    /// end of the buffer. Streaming readers read from the underlying source
+    /// until enough bytes are buffered. This is useful in combination with
+    /// [`Reader::peek()`] and [`Reader::peekn()`]. If not enough bytes are
+    /// available, the error [`Error::BufferTooShort`] is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::Reader;
+    ///
+    /// let d: &[u8] = &[69, 88];
+    /// let mut b = Reader::from_reader(d);
+    /// assert_eq!(b.peekn::<2>(), None);
+    ///
+    /// b.fill(2).unwrap();
+    /// assert_eq!(b.peekn::<2>(), Some([69, 88]));
+    /// ```
+    pub fn fill(&mut self, nbytes: usize) -> Result<()> {
+        match &mut self.stream {
+            Some(stream) => stream.fill(nbytes),
+            None => {
+                ensure!(nbytes <= self.rest.len(), BufferTooShortSnafu);
+                Ok(())
+            }
         }
     }
 
@@ -74,12 +359,7 @@ impl<'a> Reader<'a> {
     /// assert_eq!(b.pop(), Err(Error::BufferTooShort));
     /// ```
     pub fn pop(&mut self) -> Result<u8> {
-        if let Some((first, rest)) = self.rest.split_first() {
-            self.rest = rest;
-            return Ok(*first);
-        }
-
-        BufferTooShortSnafu.fail()
+        Ok(self.read_slice(1)?[0])
     }
 
     /// Pop off a byte from the front of the buffer without returning the byte.
@@ -89,24 +369,19 @@ impl<'a> Reader<'a> {
         Ok(())
     }
 
+    /// Resets the cursor to the start of the buffer. This is a no-op for
+    /// streaming readers.
     pub fn reset(&mut self) {
-        self.rest = self.buf;
+        if self.stream.is_none() {
+            self.rest = self.buf;
+        }
     }
 
     /// Pop off `n` bytes from the front of the buffer but do not return the
     /// popped off bytes. This is rarely useful other than in combination with
     /// `peekn()`.
     pub fn skipn(&mut self, n: usize) -> Result<()> {
-        // Ensure the buffer is long enough to skip n bytes.
-        ensure!(n <= self.len(), BufferTooShortSnafu);
-
-        if n == 1 {
-            return self.skip();
-        }
-
-        let (_, rest) = self.rest.split_at(n);
-        self.rest = rest;
-
+        self.read_slice(n)?;
         Ok(())
     }
 
@@ -125,7 +400,7 @@ impl<'a> Reader<'a> {
     /// assert_eq!(b.peek(), None);
     /// ```
     pub fn peek(&self) -> Option<u8> {
-        self.rest.first().copied()
+        self.remaining().first().copied()
     }
 
     /// Peek the next `n` bytes of the buffer. If the buffer is empty
@@ -143,7 +418,7 @@ impl<'a> Reader<'a> {
     /// assert_eq!(b.peek(), None);
     /// ```
     pub fn peekn<const N: usize>(&self) -> Option<[u8; N]> {
-        match self.rest.get(0..N) {
+        match self.remaining().get(0..N) {
             Some(s) => match TryInto::<[u8; N]>::try_into(s) {
                 Ok(b) => Some(b),
                 Err(_) => None,
@@ -152,9 +427,18 @@ impl<'a> Reader<'a> {
         }
     }
 
+    /// Returns `true` if this is a streaming reader, created by
+    /// [`Reader::from_reader()`].
+    pub fn is_streaming(&self) -> bool {
+        self.stream.is_some()
+    }
+
     /// Jumps back to offset `index`. Jumping beyond the current offset is not
-    /// permitted and returns [`Error::InvalidJump`].
+    /// permitted and returns [`Error::InvalidJump`]. Streaming readers don't
+    /// support jumping and return [`Error::JumpUnsupported`].
     pub fn jump_to(&mut self, index: usize) -> Result<()> {
+        ensure!(self.stream.is_none(), JumpUnsupportedSnafu);
+
         // Ensure we don't jump to ann index larger than the currennt offset.
         ensure!(
             index <= self.offset(),
@@ -208,10 +492,14 @@ impl<'a> Reader<'a> {
     /// assert_eq!(b.offset(), 1);
     /// ```
     pub fn offset(&self) -> usize {
-        self.buf.len() - self.rest.len()
+        match &self.stream {
+            Some(stream) => stream.discarded + stream.pos,
+            None => self.buf.len() - self.rest.len(),
+        }
     }
 
-    /// Returns the length of the remaining buffer.
+    /// Returns the length of the remaining buffer. For streaming readers,
+    /// this is the number of already buffered bytes.
     ///
     /// ### Example
     ///
@@ -225,7 +513,7 @@ impl<'a> Reader<'a> {
     /// assert_eq!(b.len(), 1);
     /// ```
     pub fn len(&self) -> usize {
-        self.rest.len()
+        self.remaining().len()
     }
 
     /// Returns if the buffer is empty.
@@ -242,7 +530,7 @@ impl<'a> Reader<'a> {
     /// assert_eq!(b.is_empty(), true);
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.rest.is_empty()
+        self.remaining().is_empty()
     }
 
     /// Read a character string with an optional maximum length of `max_len`.
@@ -258,6 +546,9 @@ impl<'a> Reader<'a> {
     /// If the peek returns [`None`] indicating we reached the end of the
     /// buffer the error [`ReadError::BufferTooShort`] is returned.
     ///
+    /// Use [`Reader::read_length_prefixed()`] to read byte strings with wider
+    /// or little endian length prefixes.
+    ///
     /// ### Example
     ///
     /// ```
@@ -282,6 +573,7 @@ impl<'a> Reader<'a> {
     /// assert_eq!(b.len(), 8);
     /// ```
     pub fn read_char_string(&mut self, max_len: Option<u8>) -> Result<&[u8]> {
+        self.fill(1)?;
         let len = self.peek().context(BufferTooShortSnafu)? as usize;
 
         if let Some(max_len) = max_len {
@@ -292,6 +584,354 @@ impl<'a> Reader<'a> {
         self.read_slice(len)
     }
 
+    /// Read a byte string which is prefixed with its length. The length
+    /// prefix is of type `L` and read with the endianness `E`. Any type which
+    /// can be converted into a `usize` can be used as the prefix, like `u8`,
+    /// `u16`, `u32` or the variable-length integers [`Uleb128`] and
+    /// [`QuicVarInt`]. The returned slice borrows from the buffer, no bytes
+    /// are copied.
+    ///
+    /// If the length exceeds the optional `max_len`, the error
+    /// [`Error::MaxLengthOverflow`] is returned. Without `max_len`, the length
+    /// is checked against the limit set by [`Reader::set_length_limit()`]
+    /// instead. Both checks happen before any bytes of the string are read,
+    /// so that untrusted prefixes don't cause large allocations in streaming
+    /// readers. On error, the cursor is moved back to the start of the length
+    /// prefix. Streaming readers can only do so while the prefix is still
+    /// buffered.
+    ///
+    /// [`Uleb128`]: crate::varint::Uleb128
+    /// [`QuicVarInt`]: crate::varint::QuicVarInt
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{read::Error, BigEndian, LittleEndian, Reader};
+    ///
+    /// let d = &[0, 4, 88, 65, 77, 80, 2, 0, 0, 0, 76, 69];
+    /// let mut b = Reader::new(d);
+    ///
+    /// assert_eq!(
+    ///     b.read_length_prefixed::<u16, BigEndian>(Some(3)),
+    ///     Err(Error::MaxLengthOverflow)
+    /// );
+    /// assert_eq!(
+    ///     b.read_length_prefixed::<u16, BigEndian>(None),
+    ///     Ok([88, 65, 77, 80].as_slice())
+    /// );
+    /// assert_eq!(
+    ///     b.read_length_prefixed::<u32, LittleEndian>(Some(2)),
+    ///     Ok([76, 69].as_slice())
+    /// );
+    /// ```
+    pub fn read_length_prefixed<L, E>(&mut self, max_len: Option<usize>) -> Result<&[u8]>
+    where
+        L: Read,
+        usize: TryFrom<L>,
+        E: Endianness,
+    {
+        let start = self.offset();
+
+        let result = L::read::<E>(self).and_then(|len| {
+            let len = usize::try_from(len).map_err(|_| MaxLengthOverflowSnafu.build())?;
+            let max_len = max_len.unwrap_or(self.length_limit);
+
+            ensure!(len <= max_len, MaxLengthOverflowSnafu);
+
+            self.fill(len)?;
+            Ok(len)
+        });
+
+        match result {
+            Ok(len) => self.read_slice(len),
+            Err(err) => {
+                self.rewind(start)?;
+                Err(err)
+            }
+        }
+    }
+
+    /// Read a NUL-terminated string (C string). The returned slice doesn't
+    /// include the terminator, which is consumed nonetheless. The string can
+    /// be at most `max_len` bytes long, excluding the terminator.
+    ///
+    /// The parameter `terminator` controls how a missing terminator is
+    /// handled. With [`Terminator::Required`], the error
+    /// [`Error::MaxLengthOverflow`] is returned if no terminator is found
+    /// within `max_len` bytes and [`Error::BufferTooShort`] if the end of
+    /// the buffer is reached first. With [`Terminator::Optional`], the string
+    /// ends after `max_len` bytes or at the end of the buffer. Failed reads
+    /// don't consume any bytes.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{read::{Error, Terminator}, Reader};
+    ///
+    /// let d = b"boot\0file";
+    /// let mut b = Reader::new(d);
+    ///
+    /// assert_eq!(b.read_cstring(None, Terminator::Required), Ok(b"boot".as_slice()));
+    /// assert_eq!(
+    ///     b.read_cstring(None, Terminator::Required),
+    ///     Err(Error::BufferTooShort)
+    /// );
+    /// assert_eq!(b.read_cstring(None, Terminator::Optional), Ok(b"file".as_slice()));
+    /// ```
+    pub fn read_cstring(
+        &mut self,
+        max_len: Option<usize>,
+        terminator: Terminator,
+    ) -> Result<&[u8]> {
+        let (len, terminated) = self.cstring_len(max_len, terminator)?;
+
+        if terminated {
+            let s = self.read_slice(len + 1)?;
+            Ok(&s[..len])
+        } else {
+            self.read_slice(len)
+        }
+    }
+
+    /// Read a NUL-terminated string like [`Reader::read_cstring()`], which
+    /// borrows from the input instead of the [`Reader`]. Streaming readers
+    /// don't support borrowing and return the error
+    /// [`Error::BorrowUnsupported`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{read::Terminator, Reader};
+    ///
+    /// let d = b"boot\0file";
+    /// let s = {
+    ///     let mut b = Reader::new(d);
+    ///     b.read_cstring_ref(None, Terminator::Required).unwrap()
+    /// };
+    ///
+    /// assert_eq!(s, b"boot");
+    /// ```
+    pub fn read_cstring_ref(
+        &mut self,
+        max_len: Option<usize>,
+        terminator: Terminator,
+    ) -> Result<&'a [u8]> {
+        ensure!(self.stream.is_none(), BorrowUnsupportedSnafu);
+        let (len, terminated) = self.cstring_len(max_len, terminator)?;
+
+        if terminated {
+            let s = self.read_slice_ref(len + 1)?;
+            Ok(&s[..len])
+        } else {
+            self.read_slice_ref(len)
+        }
+    }
+
+    /// Returns the length of the NUL-terminated string at the cursor and if
+    /// it is terminated, without consuming any bytes. See
+    /// [`Reader::read_cstring()`].
+    fn cstring_len(
+        &mut self,
+        max_len: Option<usize>,
+        terminator: Terminator,
+    ) -> Result<(usize, bool)> {
+        // The terminator is allowed to follow the last byte of the string
+        let limit = max_len.map_or(usize::MAX, |max_len| max_len.saturating_add(1));
+        let mut searched = 0;
+
+        loop {
+            let remaining = self.remaining();
+            let window = &remaining[..remaining.len().min(limit)];
+
+            if let Some(pos) = window[searched..].iter().position(|b| *b == 0) {
+                return Ok((searched + pos, true));
+            }
+
+            searched = window.len();
+
+            if searched == limit {
+                ensure!(terminator == Terminator::Optional, MaxLengthOverflowSnafu);
+                return Ok((limit - 1, false));
+            }
+
+            match self.fill(searched + 1) {
+                Ok(_) => continue,
+                Err(Error::BufferTooShort) if terminator == Terminator::Optional => {
+                    return Ok((searched, false))
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Read a fixed-width field of `width` bytes which is padded with `pad`
+    /// bytes, like NUL or space padded fields in tar headers or the DHCP
+    /// `sname` and `file` fields. All `width` bytes are consumed, trailing
+    /// `pad` bytes are removed from the returned slice.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::Reader;
+    ///
+    /// let d = b"tftp\0\0\0\0root    ";
+    /// let mut b = Reader::new(d);
+    ///
+    /// assert_eq!(b.read_padded(8, 0), Ok(b"tftp".as_slice()));
+    /// assert_eq!(b.read_padded(8, b' '), Ok(b"root".as_slice()));
+    /// assert!(b.is_empty());
+    /// ```
+    pub fn read_padded(&mut self, width: usize, pad: u8) -> Result<&[u8]> {
+        let s = self.read_slice(width)?;
+        let len = s.iter().rposition(|b| *b != pad).map_or(0, |pos| pos + 1);
+
+        Ok(&s[..len])
+    }
+
+    /// Read a fixed-width padded field like [`Reader::read_padded()`], which
+    /// borrows from the input instead of the [`Reader`]. Streaming readers
+    /// don't support borrowing and return the error
+    /// [`Error::BorrowUnsupported`].
+    pub fn read_padded_ref(&mut self, width: usize, pad: u8) -> Result<&'a [u8]> {
+        let s = self.read_slice_ref(width)?;
+        let len = s.iter().rposition(|b| *b != pad).map_or(0, |pos| pos + 1);
+
+        Ok(&s[..len])
+    }
+
+    /// Read a string of `nbytes` bytes which is validated according to
+    /// `policy`. Valid strings borrow from the buffer, only
+    /// [`StringPolicy::Lossy`] allocates if the bytes are not valid UTF-8.
+    /// If the string is rejected, the error [`Error::NonAsciiData`] or
+    /// [`Error::InvalidUtf8`] is returned with the offset of the offending
+    /// byte and no bytes are consumed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{read::Error, string::StringPolicy, Reader};
+    ///
+    /// let d = "grüß".as_bytes();
+    /// let mut b = Reader::new(d);
+    ///
+    /// assert_eq!(
+    ///     b.read_str(6, StringPolicy::Ascii),
+    ///     Err(Error::NonAsciiData { offset: 2 })
+    /// );
+    /// assert_eq!(b.read_str(6, StringPolicy::Utf8), Ok("grüß".into()));
+    /// ```
+    pub fn read_str(&mut self, nbytes: usize, policy: StringPolicy) -> Result<Cow<'_, str>> {
+        self.fill(nbytes)?;
+        policy.check(&self.remaining()[..nbytes], self.offset())?;
+
+        let s = self.read_slice(nbytes)?;
+        Ok(policy.decode(s))
+    }
+
+    /// Read a string which is prefixed with its length, see
+    /// [`Reader::read_length_prefixed()`]. The string is validated according
+    /// to `policy`, see [`Reader::read_str()`]. On error, the cursor is moved
+    /// back to the start of the length prefix.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{read::Error, string::StringPolicy, BigEndian, Reader};
+    ///
+    /// let d = &[0, 3, 102, 0xFF, 111];
+    /// let mut b = Reader::new(d);
+    ///
+    /// assert_eq!(
+    ///     b.read_str_prefixed::<u16, BigEndian>(None, StringPolicy::Utf8),
+    ///     Err(Error::InvalidUtf8 { offset: 3 })
+    /// );
+    /// assert_eq!(
+    ///     b.read_str_prefixed::<u16, BigEndian>(None, StringPolicy::Lossy),
+    ///     Ok("f\u{FFFD}o".into())
+    /// );
+    /// ```
+    pub fn read_str_prefixed<L, E>(
+        &mut self,
+        max_len: Option<usize>,
+        policy: StringPolicy,
+    ) -> Result<Cow<'_, str>>
+    where
+        L: Read,
+        usize: TryFrom<L>,
+        E: Endianness,
+    {
+        let start = self.offset();
+        let len = self.read_length_prefixed::<L, E>(max_len)?.len();
+
+        // The string is still buffered, move back to validate it before it
+        // is consumed
+        let offset = self.offset() - len;
+        self.rewind(offset)?;
+
+        if let Err(err) = policy.check(&self.remaining()[..len], offset) {
+            self.rewind(start)?;
+            return Err(err);
+        }
+
+        let s = self.read_slice(len)?;
+        Ok(policy.decode(s))
+    }
+
+    /// Sets the [`StringPolicy`] used when reading [`String`]s. The default
+    /// policy is [`StringPolicy::Ascii`].
+    pub fn set_string_policy(&mut self, policy: StringPolicy) {
+        self.strings = policy;
+    }
+
+    /// Returns the [`StringPolicy`] used when reading [`String`]s.
+    pub fn string_policy(&self) -> StringPolicy {
+        self.strings
+    }
+
+    /// Sets the [`StringPrefix`] used when reading [`String`]s. The default
+    /// is [`StringPrefix::None`], in which case reading a [`String`] consumes
+    /// the remaining input. Streaming readers then read from the underlying
+    /// source until it reaches its end, which blocks on sources like a
+    /// `TcpStream` until the peer closes the connection. Use a length prefix
+    /// when reading strings from streams.
+    pub fn set_string_prefix(&mut self, prefix: StringPrefix) {
+        self.string_prefix = prefix;
+    }
+
+    /// Returns the [`StringPrefix`] used when reading [`String`]s.
+    pub fn string_prefix(&self) -> StringPrefix {
+        self.string_prefix
+    }
+
+    /// Sets the maximum length of byte strings read by
+    /// [`Reader::read_length_prefixed()`] without an explicit `max_len`. This
+    /// includes [`String`]s read with a [`StringPrefix`]. The default limit
+    /// is 8 MiB.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{read::Error, BigEndian, Reader};
+    ///
+    /// let d: &[u8] = &[255, 255, 255, 255, 69, 88];
+    /// let mut b = Reader::from_reader(d);
+    /// b.set_length_limit(1024);
+    ///
+    /// assert_eq!(
+    ///     b.read_length_prefixed::<u32, BigEndian>(None),
+    ///     Err(Error::MaxLengthOverflow)
+    /// );
+    /// ```
+    pub fn set_length_limit(&mut self, limit: usize) {
+        self.length_limit = limit;
+    }
+
+    /// Returns the maximum length of byte strings read by
+    /// [`Reader::read_length_prefixed()`] without an explicit `max_len`.
+    pub fn length_limit(&self) -> usize {
+        self.length_limit
+    }
+
     /// Read a slice of bytes with the length `nbytes` from the buffer. If the
     /// number of requested bytes overflow the buffer length, an error is
     /// returned.
@@ -308,7 +948,46 @@ impl<'a> Reader<'a> {
     /// assert_eq!(b.len(), 4);
     /// ```
     pub fn read_slice(&mut self, nbytes: usize) -> Result<&[u8]> {
-        ensure!(nbytes <= self.len(), BufferTooShortSnafu);
+        self.fill(nbytes)?;
+
+        match &mut self.stream {
+            Some(stream) => {
+                let start = stream.pos;
+                stream.pos += nbytes;
+
+                Ok(&stream.buf[start..stream.pos])
+            }
+            None => {
+                let (slice, rest) = self.rest.split_at(nbytes);
+                self.rest = rest;
+
+                Ok(slice)
+            }
+        }
+    }
+
+    /// Read a slice of bytes with the length `nbytes` which borrows from the
+    /// input instead of the [`Reader`]. The slice can thus outlive the
+    /// reader, which is used by [`ReadRef`] implementations. Streaming
+    /// readers don't support borrowing and return the error
+    /// [`Error::BorrowUnsupported`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::Reader;
+    ///
+    /// let d = &[69, 88, 65, 77, 80, 76, 69, 33];
+    /// let s = {
+    ///     let mut b = Reader::new(d);
+    ///     b.read_slice_ref(4).unwrap()
+    /// };
+    ///
+    /// assert_eq!(s, &[69, 88, 65, 77]);
+    /// ```
+    pub fn read_slice_ref(&mut self, nbytes: usize) -> Result<&'a [u8]> {
+        ensure!(self.stream.is_none(), BorrowUnsupportedSnafu);
+        ensure!(nbytes <= self.rest.len(), BufferTooShortSnafu);
 
         let (slice, rest) = self.rest.split_at(nbytes);
         self.rest = rest;
@@ -332,11 +1011,337 @@ impl<'a> Reader<'a> {
     pub fn read_vec(&mut self, nbytes: usize) -> Result<Vec<u8>> {
         self.read_slice(nbytes).map(ToOwned::to_owned)
     }
+
+    /// Read a domain name in DNS wire format and return its labels. A domain
+    /// name is a sequence of length-prefixed labels, terminated by the null
+    /// label. The sequence can end with a compression pointer (two bytes
+    /// starting with `0b11`) instead, which points to the offset of another
+    /// label sequence earlier in the buffer (RFC 1035, section 4.1.4).
+    ///
+    /// Compression pointers are followed using [`Reader::jump_to()`]. Once
+    /// the name is read, the cursor is positioned right after the first
+    /// compression pointer, or after the null label if the name is not
+    /// compressed. Jump indices stored before the call are left untouched.
+    ///
+    /// The following errors can be returned, in which case the cursor is
+    /// restored to the start of the name (streaming readers only restore the
+    /// cursor if the start of the name is still buffered):
+    ///
+    /// - [`Error::PointerLoop`] if a compression pointer points to an offset
+    ///   which was already visited.
+    /// - [`Error::NameTooLong`] if the name exceeds 255 bytes.
+    /// - [`Error::InvalidLabelType`] if a label starts with the reserved bit
+    ///   patterns `0b01` or `0b10`.
+    /// - [`Error::InvalidJump`] if a compression pointer points forward.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::Reader;
+    ///
+    /// let d = &[
+    ///     7, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 3, b'c', b'o', b'm', 0,
+    ///     3, b'w', b'w', b'w', 0xC0, 0x00, 69,
+    /// ];
+    /// let mut b = Reader::new(d);
+    ///
+    /// let name = b.read_domain_name().unwrap();
+    /// assert_eq!(name, vec![b"example".to_vec(), b"com".to_vec()]);
+    ///
+    /// let name = b.read_domain_name().unwrap();
+    /// assert_eq!(name, vec![b"www".to_vec(), b"example".to_vec(), b"com".to_vec()]);
+    ///
+    /// assert_eq!(b.pop(), Ok(69));
+    /// ```
+    pub fn read_domain_name(&mut self) -> Result<Vec<Vec<u8>>> {
+        let start = self.offset();
+        let depth = self.jump_indices.len();
+
+        let result = self.read_domain_name_labels();
+
+        // Restore the cursor to the position right after the first
+        // compression pointer and drop all jump indices stored while
+        // following pointers.
+        if let Some(index) = self.jump_indices.get(depth).copied() {
+            self.jump_indices.truncate(depth);
+            self.rest = &self.buf[index..];
+        }
+
+        if result.is_err() {
+            self.rewind(start)?;
+        }
+
+        result
+    }
+
+    /// Returns the remaining (buffered) bytes after the cursor.
+    pub(crate) fn remaining(&self) -> &[u8] {
+        match &self.stream {
+            Some(stream) => &stream.buf[stream.pos..],
+            None => self.rest,
+        }
+    }
+
+    /// Moves the cursor back to `offset`. Streaming readers can only move
+    /// the cursor back to bytes which are still buffered and return the error
+    /// [`Error::RewindUnsupported`] otherwise.
+    pub(crate) fn rewind(&mut self, offset: usize) -> Result<()> {
+        match &mut self.stream {
+            Some(stream) => {
+                ensure!(
+                    offset >= stream.discarded,
+                    RewindUnsupportedSnafu { offset }
+                );
+                stream.pos = offset - stream.discarded;
+            }
+            None => self.rest = &self.buf[offset..],
+        }
+
+        Ok(())
+    }
+
+    /// Buffers the remaining input of streaming readers and returns the
+    /// number of bytes left. Streaming readers block until the underlying
+    /// source reaches its end.
+    pub(crate) fn fill_to_end(&mut self) -> Result<usize> {
+        loop {
+            let len = self.remaining().len();
+
+            match self.fill(len + 1) {
+                Ok(_) => continue,
+                Err(Error::BufferTooShort) => return Ok(len),
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    fn read_domain_name_labels(&mut self) -> Result<Vec<Vec<u8>>> {
+        let mut labels = Vec::new();
+        let mut visited = Vec::new();
+
+        // The null label accounts for one byte of the total length
+        let mut len = 1;
+
+        loop {
+            let offset = self.offset();
+            let byte = self.pop()?;
+
+            match byte & 0xC0 {
+                0x00 => {
+                    if byte == 0 {
+                        return Ok(labels);
+                    }
+
+                    len += byte as usize + 1;
+                    ensure!(len <= MAX_DOMAIN_NAME_LEN, NameTooLongSnafu);
+
+                    labels.push(self.read_vec(byte as usize)?);
+                }
+                0xC0 => {
+                    let index = u16::from_be_bytes([byte & 0x3F, self.pop()?]) as usize;
+
+                    ensure!(!visited.contains(&index), PointerLoopSnafu { index });
+                    visited.push(index);
+
+                    self.jump_to(index)?;
+                }
+                _ => return InvalidLabelTypeSnafu { byte, offset }.fail(),
+            }
+        }
+    }
+
+    /// Returns a [`BitReader`] which reads individual bits from this reader
+    /// using the bit order `order`. See [`BitReader`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{BitOrder, Reader};
+    ///
+    /// let mut b = Reader::new(&[0x45, 0x00]);
+    /// let mut bits = b.bits(BitOrder::MsbFirst);
+    ///
+    /// assert_eq!(bits.read_bits(4), Ok(4));
+    /// assert_eq!(bits.read_bits(4), Ok(5));
+    /// assert_eq!(bits.read_flag(), Ok(false));
+    /// ```
+    pub fn bits(&mut self, order: BitOrder) -> BitReader<'_, 'a> {
+        BitReader::new(self, order)
+    }
+}
+
+/// A bit cursor layered on top of a [`Reader`]. It reads arbitrary N-bit
+/// unsigned values and single flags, which is useful to unpack bit fields
+/// of protocol headers, like the IPv4 version and IHL or the DNS flags.
+///
+/// Bytes are popped off the underlying [`Reader`] as soon as the first bit
+/// of them is read. A partially consumed byte is therefore already
+/// consumed from the point of view of the [`Reader`]. The remaining bits of
+/// such a byte can be skipped by calling [`BitReader::align()`].
+///
+/// ### Example
+///
+/// ```
+/// use binbuf::{BitOrder, Reader};
+///
+/// // DNS flags: QR=1, OPCODE=0, AA=0, TC=0, RD=1, RA=1, Z=0, RCODE=3
+/// let mut b = Reader::new(&[0x81, 0x83]);
+/// let mut bits = b.bits(BitOrder::MsbFirst);
+///
+/// assert_eq!(bits.read_flag(), Ok(true));
+/// assert_eq!(bits.read_bits(4), Ok(0));
+/// assert_eq!(bits.read_bits(2), Ok(0));
+/// assert_eq!(bits.read_flag(), Ok(true));
+/// assert_eq!(bits.read_flag(), Ok(true));
+/// assert_eq!(bits.read_bits(3), Ok(0));
+/// assert_eq!(bits.read_bits(4), Ok(3));
+/// ```
+#[derive(Debug)]
+pub struct BitReader<'r, 'a> {
+    reader: &'r mut Reader<'a>,
+    order: BitOrder,
+
+    /// The byte bits are currently read from.
+    byte: u8,
+
+    /// The number of bits of `byte` which are not yet read.
+    remaining: u32,
+}
+
+impl<'r, 'a> BitReader<'r, 'a> {
+    /// Create a new [`BitReader`] reading from `reader` using the bit order
+    /// `order`.
+    pub fn new(reader: &'r mut Reader<'a>, order: BitOrder) -> Self {
+        Self {
+            reader,
+            order,
+            byte: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Read `nbits` bits and return them as an unsigned value. At most 64
+    /// bits can be read at once. If `nbits` exceeds this limit, the error
+    /// [`Error::BitCountOverflow`] is returned. If there are not enough bits
+    /// left, the error [`Error::BitBufferTooShort`] is returned. In both
+    /// cases no bits are consumed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{BitOrder, Reader};
+    ///
+    /// let mut b = Reader::new(&[0b1011_0001, 0b0100_0000]);
+    ///
+    /// let mut bits = b.bits(BitOrder::MsbFirst);
+    /// assert_eq!(bits.read_bits(3), Ok(0b101));
+    /// assert_eq!(bits.read_bits(7), Ok(0b1000101));
+    ///
+    /// let mut b = Reader::new(&[0b1011_0001]);
+    ///
+    /// let mut bits = b.bits(BitOrder::LsbFirst);
+    /// assert_eq!(bits.read_bits(3), Ok(0b001));
+    /// assert_eq!(bits.read_bits(5), Ok(0b10110));
+    /// ```
+    pub fn read_bits(&mut self, nbits: u32) -> Result<u64> {
+        ensure!(
+            nbits <= u64::BITS,
+            BitCountOverflowSnafu {
+                nbits,
+                offset: self.bit_offset()
+            }
+        );
+
+        // Ensure there are enough bits left, so that we don't consume any
+        // bits when the read fails.
+        let nbytes = (nbits.saturating_sub(self.remaining) as usize).div_ceil(8);
+        match self.reader.fill(nbytes) {
+            Err(Error::BufferTooShort) => {
+                return BitBufferTooShortSnafu {
+                    nbits,
+                    offset: self.bit_offset(),
+                }
+                .fail()
+            }
+            result => result?,
+        }
+
+        let mut value = 0u64;
+        let mut left = nbits;
+
+        while left > 0 {
+            if self.remaining == 0 {
+                self.byte = self.reader.pop()?;
+                self.remaining = 8;
+            }
+
+            let n = left.min(self.remaining);
+            let mask = ((1u16 << n) - 1) as u8;
+
+            match self.order {
+                BitOrder::MsbFirst => {
+                    let bits = (self.byte >> (self.remaining - n)) & mask;
+                    value = (value << n) | bits as u64;
+                }
+                BitOrder::LsbFirst => {
+                    let bits = (self.byte >> (8 - self.remaining)) & mask;
+                    value |= (bits as u64) << (nbits - left);
+                }
+            }
+
+            self.remaining -= n;
+            left -= n;
+        }
+
+        Ok(value)
+    }
+
+    /// Read a single bit and return it as a flag.
+    pub fn read_flag(&mut self) -> Result<bool> {
+        Ok(self.read_bits(1)? == 1)
+    }
+
+    /// Skip the remaining bits of the current byte, so that the next read
+    /// starts at the next byte boundary. Returns the number of skipped bits.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{BitOrder, Reader};
+    ///
+    /// let mut b = Reader::new(&[0xF0, 0x45]);
+    /// let mut bits = b.bits(BitOrder::MsbFirst);
+    ///
+    /// assert_eq!(bits.read_bits(2), Ok(3));
+    /// assert_eq!(bits.align(), 6);
+    /// assert_eq!(bits.read_bits(8), Ok(0x45));
+    /// ```
+    pub fn align(&mut self) -> u32 {
+        let skipped = self.remaining;
+        self.remaining = 0;
+        skipped
+    }
+
+    /// Returns if the cursor is positioned at a byte boundary.
+    pub fn is_aligned(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Returns the current offset in bits, relative to the start of the
+    /// underlying [`Reader`].
+    pub fn bit_offset(&self) -> usize {
+        self.reader.offset() * 8 - self.remaining as usize
+    }
+
+    /// Returns the bit order of this [`BitReader`].
+    pub fn order(&self) -> BitOrder {
+        self.order
+    }
 }
 
 /// All types which implement this trait can be constructed by reading from
-/// a [`ReadBuffer`]. An implementation for all sized unsigned integers is
-/// provided.
+/// a [`ReadBuffer`]. An implementation for all sized signed and unsigned
+/// integers is provided.
 ///
 /// ### Example
 ///
@@ -349,6 +1354,11 @@ impl<'a> Reader<'a> {
 /// assert_eq!(u16::read::<BigEndian>(&mut b), Ok(17752));
 /// ```
 pub trait Read: Sized {
+    /// The byte orders supported by this type. Reading with an unsupported
+    /// [`Endianness`] returns the error [`Error::BigEndianNotSupported`] or
+    /// [`Error::LittleEndianNotSupported`].
+    const ENDIANNESS: SupportedEndianness = SupportedEndianness::Both;
+
     /// Read [`Self`] from a [`Reader`].
     ///
     /// ### Example
@@ -363,6 +1373,7 @@ pub trait Read: Sized {
     /// assert_eq!(i, 17752);
     /// ```
     fn read<E: Endianness>(buf: &mut Reader) -> Result<Self> {
+        Self::ENDIANNESS.verify_read::<E>()?;
         E::read(buf)
     }
 
@@ -410,9 +1421,135 @@ pub trait Read: Sized {
     }
 }
 
+/// Read [`Self`] from a [`Reader`] while borrowing from its input. This is
+/// the borrowing counterpart of [`Read`], which allows types to hold
+/// references like `&'a [u8]` or `&'a str` into the input buffer instead of
+/// copying the data. All types implementing [`Read`] implement [`ReadRef`]
+/// as well.
+///
+/// Byte slices and strings use the [`StringPolicy`] and [`StringPrefix`] of
+/// the reader. `&'a str` can't replace invalid UTF-8 sequences, use
+/// `Cow<'a, str>` to read strings with [`StringPolicy::Lossy`]. Only
+/// slice-based readers support borrowing, streaming readers return the
+/// error [`Error::BorrowUnsupported`].
+///
+/// Deriving `Read` for a struct with a lifetime parameter implements this
+/// trait.
+///
+/// ### Example
+///
+/// ```
+/// use binbuf::{read::ReadRef, string::StringPrefix, BigEndian, Reader};
+///
+/// let d = &[0, 2, 104, 105, 1, 69];
+/// let mut b = Reader::new(d);
+/// b.set_string_prefix(StringPrefix::U16);
+///
+/// assert_eq!(<&str>::read_ref::<BigEndian>(&mut b), Ok("hi"));
+/// assert_eq!(<&[u8]>::read_ref::<BigEndian>(&mut b), Err(binbuf::read::Error::BufferTooShort));
+/// assert_eq!(u8::read_ref::<BigEndian>(&mut b), Ok(1));
+/// ```
+pub trait ReadRef<'a>: Sized {
+    /// Read [`Self`] from a [`Reader`] with the endianness `E`.
+    fn read_ref<E: Endianness>(buf: &mut Reader<'a>) -> Result<Self>;
+}
+
+impl<'a, T: Read> ReadRef<'a> for T {
+    fn read_ref<E: Endianness>(buf: &mut Reader<'a>) -> Result<Self> {
+        T::read::<E>(buf)
+    }
+}
+
+/// Read [`Self`] from a [`Reader`] and return a custom error type. This is
+/// the fallible counterpart of [`Read`], which allows types to return domain
+/// validation errors in addition to the errors of the [`Reader`]. All types
+/// implementing [`Read`] implement [`TryRead`] as well, with [`Error`] as
+/// their error type.
+///
+/// Deriving `Read` with the `#[binbuf(error = "...")]` container attribute
+/// implements this trait. The fields are read with [`TryRead`] and their
+/// errors are converted into the custom error using [`From`].
+///
+/// ### Example
+///
+/// ```
+/// use binbuf::{read::{self, TryRead}, BigEndian, Endianness, Read as _, Reader};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum PortError {
+///     Reserved,
+///     Read(read::Error),
+/// }
+///
+/// impl From<read::Error> for PortError {
+///     fn from(err: read::Error) -> Self {
+///         Self::Read(err)
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Port(u16);
+///
+/// impl TryRead for Port {
+///     type Error = PortError;
+///
+///     fn try_read<E: Endianness>(buf: &mut Reader) -> Result<Self, Self::Error> {
+///         match u16::read::<E>(buf)? {
+///             0 => Err(PortError::Reserved),
+///             port => Ok(Port(port)),
+///         }
+///     }
+/// }
+///
+/// let d = &[0, 53, 0, 0, 1];
+/// let mut b = Reader::new(d);
+///
+/// assert_eq!(Port::try_read::<BigEndian>(&mut b), Ok(Port(53)));
+/// assert_eq!(Port::try_read::<BigEndian>(&mut b), Err(PortError::Reserved));
+/// assert_eq!(
+///     Port::try_read::<BigEndian>(&mut b),
+///     Err(PortError::Read(read::Error::BufferTooShort))
+/// );
+/// ```
+pub trait TryRead: Sized {
+    /// The error returned when reading fails.
+    type Error: From<Error>;
+
+    /// Read [`Self`] from a [`Reader`] with the endianness `E`.
+    fn try_read<E: Endianness>(buf: &mut Reader) -> Result<Self, Self::Error>;
+}
+
+impl<T: Read> TryRead for T {
+    type Error = Error;
+
+    fn try_read<E: Endianness>(buf: &mut Reader) -> Result<Self> {
+        T::read::<E>(buf)
+    }
+}
+
+/// Types whose encoding is selected by a tag which is read separately, like
+/// the record data of DNS resource records, which is selected by the type
+/// field. Deriving `Read` on enums with variants carrying data implements
+/// this trait, the tag is the enum `repr`.
+///
+/// A derived `other` variant preserves unknown tags but reads no payload.
+/// It is only supported with `tag = "external"`, the caller has to skip the
+/// payload of unknown tags using its own framing, like a length field.
+pub trait ReadPayload: Sized {
+    /// The type of the tag selecting the encoding.
+    type Tag;
+
+    /// The error returned when reading fails.
+    type Error: From<Error>;
+
+    /// Read the [`Self`] variant selected by `tag` from a [`Reader`] with the
+    /// endianness `E`.
+    fn read_payload<E: Endianness>(tag: Self::Tag, buf: &mut Reader) -> Result<Self, Self::Error>;
+}
+
 /// Multiple values of types which implement this trait can be read at once
-/// from a [`ReadBuffer`]. An implementation for all sized unsigned integers is
-/// provided.
+/// from a [`ReadBuffer`]. An implementation for all sized signed and unsigned
+/// integers is provided.
 ///
 /// ### Example
 ///
@@ -469,3 +1606,12 @@ from_buffer_and_readable_impl!(u16, 2);
 from_buffer_and_readable_impl!(u32, 4);
 from_buffer_and_readable_impl!(u64, 8);
 from_buffer_and_readable_impl!(u128, 16);
+
+from_buffer_and_readable_impl!(i8, 1);
+from_buffer_and_readable_impl!(i16, 2);
+from_buffer_and_readable_impl!(i32, 4);
+from_buffer_and_readable_impl!(i64, 8);
+from_buffer_and_readable_impl!(i128, 16);
+
+from_buffer_and_readable_impl!(f32, 4);
+from_buffer_and_readable_impl!(f64, 8);
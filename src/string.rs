@@ -0,0 +1,130 @@
+//! Text string support. [`String`] and `&str` implement [`Write`] and
+//! [`String`] implements [`Read`]. Borrowed strings and byte slices can be
+//! read using [`ReadRef`]. How strings are validated and framed is
+//! configured per [`Reader`] and [`Writer`]:
+//!
+//! - [`StringPolicy`] selects which bytes are accepted: ASCII only, valid
+//!   UTF-8, or anything with invalid UTF-8 sequences replaced (lossy).
+//! - [`StringPrefix`] selects the length prefix written in front of the
+//!   string. Without a prefix, reading a [`String`] consumes the remaining
+//!   input.
+//!
+//! Both default to the previous behaviour of `Write for String`: ASCII only
+//! and no length prefix.
+//!
+//! Invalid strings are reported as [`read::Error::NonAsciiData`] or
+//! [`read::Error::InvalidUtf8`], which include the offset of the offending
+//! byte in the input.
+//!
+//! ### Example
+//!
+//! ```
+//! use binbuf::{
+//!     string::{StringPolicy, StringPrefix},
+//!     BigEndian, Read as _, Reader, Write as _, Writer,
+//! };
+//!
+//! let mut b = Writer::new();
+//! b.set_string_policy(StringPolicy::Utf8);
+//! b.set_string_prefix(StringPrefix::U8);
+//!
+//! String::from("grüß").write::<BigEndian>(&mut b).unwrap();
+//! assert_eq!(b.bytes(), &[6, 103, 114, 195, 188, 195, 159]);
+//!
+//! let mut r = Reader::new(b.bytes());
+//! r.set_string_policy(StringPolicy::Utf8);
+//! r.set_string_prefix(StringPrefix::U8);
+//!
+//! assert_eq!(String::read::<BigEndian>(&mut r), Ok(String::from("grüß")));
+//! ```
+//!
+//! [`Read`]: crate::Read
+//! [`ReadRef`]: crate::ReadRef
+//! [`Write`]: crate::Write
+//! [`Reader`]: crate::Reader
+//! [`Writer`]: crate::Writer
+
+use std::borrow::Cow;
+
+use crate::read::{self, InvalidUtf8Snafu, NonAsciiDataSnafu};
+
+/// Controls which bytes are accepted when reading and writing strings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StringPolicy {
+    /// Only ASCII strings are accepted. Reading non-ASCII bytes returns the
+    /// error [`read::Error::NonAsciiData`], writing non-ASCII strings returns
+    /// the error [`WriteError::NonAsciiData`].
+    ///
+    /// [`WriteError::NonAsciiData`]: crate::write::WriteError::NonAsciiData
+    #[default]
+    Ascii,
+
+    /// Strings have to be valid UTF-8. Reading invalid UTF-8 returns the
+    /// error [`read::Error::InvalidUtf8`].
+    Utf8,
+
+    /// Invalid UTF-8 sequences are replaced with `U+FFFD REPLACEMENT
+    /// CHARACTER` when reading. Strings are written unchanged, like with
+    /// [`StringPolicy::Utf8`].
+    Lossy,
+}
+
+impl StringPolicy {
+    /// Checks if `bytes` are accepted by the policy. `offset` is the offset
+    /// of the first byte in the input and is used to report the offset of
+    /// the offending byte.
+    pub(crate) fn check(self, bytes: &[u8], offset: usize) -> read::Result<()> {
+        match self {
+            Self::Ascii => match bytes.iter().position(|b| !b.is_ascii()) {
+                Some(pos) => NonAsciiDataSnafu {
+                    offset: offset + pos,
+                }
+                .fail(),
+                None => Ok(()),
+            },
+            Self::Utf8 => match std::str::from_utf8(bytes) {
+                Ok(_) => Ok(()),
+                Err(err) => InvalidUtf8Snafu {
+                    offset: offset + err.valid_up_to(),
+                }
+                .fail(),
+            },
+            Self::Lossy => Ok(()),
+        }
+    }
+
+    /// Converts `bytes` which passed [`StringPolicy::check()`] into a
+    /// string. Valid UTF-8 is borrowed, only lossy conversions allocate.
+    pub(crate) fn decode(self, bytes: &[u8]) -> Cow<'_, str> {
+        String::from_utf8_lossy(bytes)
+    }
+}
+
+/// The length prefix written in front of strings and byte slices by their
+/// [`Write`] implementations and read by `Read for String` and [`ReadRef`].
+/// The prefix is encoded with the endianness the string is read or written
+/// with.
+///
+/// [`Write`]: crate::Write
+/// [`ReadRef`]: crate::ReadRef
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StringPrefix {
+    /// No length prefix. Reading consumes all remaining input. Streaming
+    /// readers block until the underlying source reaches its end.
+    #[default]
+    None,
+
+    /// A `u8` length prefix, like DNS character strings.
+    U8,
+
+    /// A `u16` length prefix.
+    U16,
+
+    /// A `u32` length prefix, like SSH strings.
+    U32,
+
+    /// A [`Uleb128`] length prefix, like protobuf strings.
+    ///
+    /// [`Uleb128`]: crate::varint::Uleb128
+    Uleb128,
+}
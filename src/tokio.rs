@@ -0,0 +1,318 @@
+//! Adapters to read and write typed values from and to async byte streams
+//! provided by [`tokio`]. This module requires the `tokio` feature.
+//!
+//! [`AsyncReader`] decodes values from any [`AsyncRead`] and [`AsyncWriter`]
+//! encodes values into any [`AsyncWrite`]. Both adapters use the existing
+//! [`Read`] and [`Write`] implementations, so every type which can be read
+//! from a [`Reader`] or written to a [`Writer`] can also be used with async
+//! streams.
+//!
+//! ### Example
+//!
+//! ```
+//! use binbuf::tokio::{AsyncReader, AsyncWriter};
+//! use binbuf::BigEndian;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let (client, server) = tokio::io::duplex(64);
+//!
+//! let mut w = AsyncWriter::new(client);
+//! w.write::<u16, BigEndian>(&17752).await.unwrap();
+//!
+//! let mut r = AsyncReader::new(server);
+//! assert_eq!(r.read::<u16, BigEndian>().await, Ok(17752));
+//! # }
+//! ```
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::{
+    read::{self, DEFAULT_LENGTH_LIMIT},
+    string::{StringPolicy, StringPrefix},
+    write::{self, FloatPolicy},
+    BigEndian, Endianness, LittleEndian, Read, Reader, Write, Writer,
+};
+
+/// The default number of bytes requested from the underlying source of an
+/// [`AsyncReader`] per refill.
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// Reads typed values from an [`AsyncRead`].
+///
+/// Bytes are read into an internal buffer. Each value is decoded from the
+/// buffered bytes using its [`Read`] implementation. If the implementation
+/// runs out of bytes, more data is read from the underlying source and
+/// decoding is retried from the start of the value. Bytes are only consumed
+/// once a value was decoded successfully.
+///
+/// Each value is decoded by a fresh [`Reader`] which starts at the first byte
+/// of the value. [`Reader::jump_to()`] can thus only be used to jump to
+/// bytes of the current value. The [`StringPolicy`], [`StringPrefix`] and
+/// length limit of the [`AsyncReader`] are passed on to each [`Reader`].
+///
+/// With [`StringPrefix::None`], the default, a [`String`] consumes all bytes
+/// which are buffered when it is read. This depends on how the underlying
+/// source delivers its data, so use a length prefix when reading strings.
+#[derive(Debug)]
+pub struct AsyncReader<R> {
+    inner: R,
+    buf: Vec<u8>,
+    pos: usize,
+    capacity: usize,
+    strings: StringPolicy,
+    string_prefix: StringPrefix,
+    length_limit: usize,
+}
+
+impl<R> AsyncReader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Create a new [`AsyncReader`] which reads from `inner`.
+    pub fn new(inner: R) -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY, inner)
+    }
+
+    /// Create a new [`AsyncReader`] which requests at least `capacity` bytes
+    /// from `inner` per refill.
+    pub fn with_capacity(capacity: usize, inner: R) -> Self {
+        Self {
+            inner,
+            buf: Vec::new(),
+            pos: 0,
+            capacity: capacity.max(1),
+            strings: StringPolicy::default(),
+            string_prefix: StringPrefix::default(),
+            length_limit: DEFAULT_LENGTH_LIMIT,
+        }
+    }
+
+    /// Read a value of type `T` with the endianness `E`. If the underlying
+    /// source reaches its end before the value could be decoded, the error
+    /// [`read::Error::BufferTooShort`] is returned. Errors of the underlying
+    /// source are returned as [`read::Error::Io`]. If decoding fails, no
+    /// bytes are consumed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{tokio::AsyncReader, BigEndian};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let d: &[u8] = &[69, 88, 65, 77, 80, 76, 69, 33];
+    /// let mut r = AsyncReader::new(d);
+    ///
+    /// assert_eq!(r.read::<u16, BigEndian>().await, Ok(17752));
+    /// assert_eq!(r.read::<u32, BigEndian>().await, Ok(1095585868));
+    /// # }
+    /// ```
+    pub async fn read<T: Read, E: Endianness>(&mut self) -> read::Result<T> {
+        loop {
+            // The reader is scoped so that it is not held across the await
+            // point below
+            let result = {
+                let mut reader = Reader::new(&self.buf[self.pos..]);
+                reader.set_string_policy(self.strings);
+                reader.set_string_prefix(self.string_prefix);
+                reader.set_length_limit(self.length_limit);
+
+                T::read::<E>(&mut reader).map(|v| (v, reader.offset()))
+            };
+
+            match result {
+                Ok((value, n)) => {
+                    self.pos += n;
+                    return Ok(value);
+                }
+                Err(read::Error::BufferTooShort | read::Error::BitBufferTooShort { .. }) => {
+                    if self.fill().await? == 0 {
+                        return Err(read::Error::BufferTooShort);
+                    }
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Read a value of type `T` in big endian byte order.
+    pub async fn read_be<T: Read>(&mut self) -> read::Result<T> {
+        self.read::<T, BigEndian>().await
+    }
+
+    /// Read a value of type `T` in little endian byte order.
+    pub async fn read_le<T: Read>(&mut self) -> read::Result<T> {
+        self.read::<T, LittleEndian>().await
+    }
+
+    /// Sets the [`StringPolicy`] used when reading [`String`]s, see
+    /// [`Reader::set_string_policy()`].
+    pub fn set_string_policy(&mut self, policy: StringPolicy) {
+        self.strings = policy;
+    }
+
+    /// Returns the [`StringPolicy`] used when reading [`String`]s.
+    pub fn string_policy(&self) -> StringPolicy {
+        self.strings
+    }
+
+    /// Sets the [`StringPrefix`] used when reading [`String`]s, see
+    /// [`Reader::set_string_prefix()`].
+    pub fn set_string_prefix(&mut self, prefix: StringPrefix) {
+        self.string_prefix = prefix;
+    }
+
+    /// Returns the [`StringPrefix`] used when reading [`String`]s.
+    pub fn string_prefix(&self) -> StringPrefix {
+        self.string_prefix
+    }
+
+    /// Sets the maximum length of length-prefixed byte strings, see
+    /// [`Reader::set_length_limit()`].
+    pub fn set_length_limit(&mut self, limit: usize) {
+        self.length_limit = limit;
+    }
+
+    /// Returns the maximum length of length-prefixed byte strings.
+    pub fn length_limit(&self) -> usize {
+        self.length_limit
+    }
+
+    /// Returns the buffered bytes which were not consumed yet.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..]
+    }
+
+    /// Returns a reference to the underlying source.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying source. Reading from
+    /// the source directly can cause buffered bytes to get out of order.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Consumes the [`AsyncReader`] and returns the underlying source.
+    /// Bytes which are still buffered are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Discards consumed bytes and reads more data from the underlying
+    /// source. Returns the number of bytes read, which is zero once the
+    /// source reached its end.
+    async fn fill(&mut self) -> read::Result<usize> {
+        self.buf.drain(..self.pos);
+        self.pos = 0;
+        self.buf.reserve(self.capacity);
+
+        loop {
+            match self.inner.read_buf(&mut self.buf).await {
+                Ok(n) => return Ok(n),
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+}
+
+/// Writes typed values to an [`AsyncWrite`].
+///
+/// Each value is first encoded into a [`Writer`] using its [`Write`]
+/// implementation. The encoded bytes are then written to the underlying sink
+/// as a whole. Values which fail to encode are never written partially.
+/// The [`FloatPolicy`] of the intermediate [`Writer`] can be set using
+/// [`AsyncWriter::set_float_policy()`].
+#[derive(Debug)]
+pub struct AsyncWriter<W> {
+    inner: W,
+    floats: FloatPolicy,
+}
+
+impl<W> AsyncWriter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Create a new [`AsyncWriter`] which writes to `inner`.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            floats: FloatPolicy::default(),
+        }
+    }
+
+    /// Write `value` with the endianness `E` and return the number of bytes
+    /// written. Errors of the underlying sink are returned as
+    /// [`write::WriteError::Io`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{tokio::AsyncWriter, BigEndian};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let mut w = AsyncWriter::new(Vec::new());
+    ///
+    /// assert_eq!(w.write::<u16, BigEndian>(&17752).await, Ok(2));
+    /// assert_eq!(w.into_inner(), vec![69, 88]);
+    /// # }
+    /// ```
+    pub async fn write<T: Write, E: Endianness>(&mut self, value: &T) -> write::Result {
+        // The writer is scoped so that it is not held across the await point
+        // below
+        let bytes = {
+            let mut buf = Writer::new();
+            buf.set_float_policy(self.floats);
+            value.write::<E>(&mut buf)?;
+            buf.owned_bytes()
+        };
+
+        self.inner.write_all(&bytes).await?;
+        Ok(bytes.len())
+    }
+
+    /// Write `value` in big endian byte order.
+    pub async fn write_be<T: Write>(&mut self, value: &T) -> write::Result {
+        self.write::<T, BigEndian>(value).await
+    }
+
+    /// Write `value` in little endian byte order.
+    pub async fn write_le<T: Write>(&mut self, value: &T) -> write::Result {
+        self.write::<T, LittleEndian>(value).await
+    }
+
+    /// Sets the [`FloatPolicy`] used when writing `f32` and `f64` values. The
+    /// default policy is [`FloatPolicy::Preserve`].
+    pub fn set_float_policy(&mut self, policy: FloatPolicy) {
+        self.floats = policy;
+    }
+
+    /// Returns the [`FloatPolicy`] used when writing `f32` and `f64` values.
+    pub fn float_policy(&self) -> FloatPolicy {
+        self.floats
+    }
+
+    /// Flush the underlying sink.
+    pub async fn flush(&mut self) -> write::Result<()> {
+        Ok(self.inner.flush().await?)
+    }
+
+    /// Returns a reference to the underlying sink.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying sink.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Consumes the [`AsyncWriter`] and returns the underlying sink.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
@@ -0,0 +1,361 @@
+//! Variable-length integer encodings. Each encoding is provided as a wrapper
+//! type which implements [`Read`] and [`Write`], so it can be used like any
+//! other type, including as a field type of structs deriving `Read` and
+//! `Write`.
+//!
+//! - [`Uleb128`] and [`Sleb128`]: Unsigned and signed LEB128, as used by
+//!   WebAssembly, DWARF and protobuf.
+//! - [`ZigZag`]: Signed integers which are zigzag encoded and then written
+//!   as unsigned LEB128, as used by protobuf `sint32` and `sint64` fields.
+//! - [`QuicVarInt`]: The variable-length integer encoding of QUIC (RFC 9000,
+//!   section 16), which uses a two bit length prefix.
+//!
+//! Variable-length integers are independent of the byte order, the
+//! endianness is ignored. LEB128 encodings which use more bytes than
+//! necessary are rejected with [`read::Error::OverlongVarInt`], values which
+//! don't fit into 64 bits are rejected with [`read::Error::VarIntOverflow`].
+//! QUIC explicitly allows overlong encodings, so [`QuicVarInt`] accepts them
+//! unless it is read with [`QuicVarInt::read_strict()`]. Failed reads don't
+//! consume any bytes.
+//!
+//! ### Example
+//!
+//! ```
+//! use binbuf::{varint::Uleb128, BigEndian, Read as _, Reader, Write as _, Writer};
+//!
+//! let mut b = Writer::new();
+//! Uleb128(624485).write::<BigEndian>(&mut b).unwrap();
+//! assert_eq!(b.bytes(), &[0xE5, 0x8E, 0x26]);
+//!
+//! let mut r = Reader::new(b.bytes());
+//! assert_eq!(Uleb128::read::<BigEndian>(&mut r), Ok(Uleb128(624485)));
+//! ```
+
+use std::num::TryFromIntError;
+
+use snafu::ensure;
+
+use crate::{
+    read::{self, OverlongVarIntSnafu, VarIntOverflowSnafu},
+    write::{self, VarIntOverflowSnafu as WriteVarIntOverflowSnafu},
+    Read, ReadableMulti, Reader, Write, Writer,
+};
+
+/// The maximum number of bytes of a LEB128 encoded 64 bit integer.
+const MAX_LEB128_LEN: usize = 10;
+
+/// The largest value which can be encoded as a QUIC varint.
+const MAX_QUIC_VALUE: u64 = (1 << 62) - 1;
+
+macro_rules! varint_type {
+    ($(#[$Meta:meta])* $Name:ident, $Inner:ty) => {
+        $(#[$Meta])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $Name(pub $Inner);
+
+        impl From<$Inner> for $Name {
+            fn from(value: $Inner) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$Name> for $Inner {
+            fn from(value: $Name) -> Self {
+                value.0
+            }
+        }
+
+        impl Read for $Name {
+            fn read_be(buf: &mut Reader) -> read::Result<Self> {
+                Self::decode(buf)
+            }
+
+            fn read_le(buf: &mut Reader) -> read::Result<Self> {
+                Self::decode(buf)
+            }
+        }
+
+        impl ReadableMulti for $Name {}
+
+        impl Write for $Name {
+            fn write_be(&self, buf: &mut Writer) -> write::Result {
+                self.encode(buf)
+            }
+
+            fn write_le(&self, buf: &mut Writer) -> write::Result {
+                self.encode(buf)
+            }
+        }
+    };
+}
+
+varint_type!(
+    /// An unsigned 64 bit integer encoded as unsigned LEB128.
+    Uleb128,
+    u64
+);
+
+varint_type!(
+    /// A signed 64 bit integer encoded as signed LEB128.
+    Sleb128,
+    i64
+);
+
+varint_type!(
+    /// A signed 64 bit integer which is zigzag encoded and then written as
+    /// unsigned LEB128. Zigzag encoding maps small negative values to small
+    /// unsigned values: 0 => 0, -1 => 1, 1 => 2, -2 => 3 and so on.
+    ZigZag,
+    i64
+);
+
+varint_type!(
+    /// An unsigned integer of at most 62 bits, encoded as QUIC
+    /// variable-length integer. The two most significant bits of the first
+    /// byte encode the length of the integer (1, 2, 4 or 8 bytes). Values
+    /// larger than `2^62 - 1` cannot be written and return the error
+    /// [`write::WriteError::VarIntOverflow`].
+    QuicVarInt,
+    u64
+);
+
+macro_rules! varint_length_impl {
+    ($Name:ident) => {
+        impl TryFrom<usize> for $Name {
+            type Error = TryFromIntError;
+
+            fn try_from(value: usize) -> Result<Self, Self::Error> {
+                u64::try_from(value).map(Self)
+            }
+        }
+
+        impl TryFrom<$Name> for usize {
+            type Error = TryFromIntError;
+
+            fn try_from(value: $Name) -> Result<Self, Self::Error> {
+                usize::try_from(value.0)
+            }
+        }
+    };
+}
+
+// The unsigned encodings can be used as length prefixes, see
+// Reader::read_length_prefixed and Writer::write_length_prefixed
+varint_length_impl!(Uleb128);
+varint_length_impl!(QuicVarInt);
+
+impl Uleb128 {
+    fn decode(buf: &mut Reader) -> read::Result<Self> {
+        let len = leb128_len(buf)?;
+        let value = decode_uleb128(&buf.remaining()[..len])?;
+
+        buf.skipn(len)?;
+        Ok(Self(value))
+    }
+
+    fn encode(&self, buf: &mut Writer) -> write::Result {
+        let mut b = [0; MAX_LEB128_LEN];
+        let len = encode_uleb128(self.0, &mut b);
+        buf.try_write(&b[..len])
+    }
+}
+
+impl Sleb128 {
+    fn decode(buf: &mut Reader) -> read::Result<Self> {
+        let len = leb128_len(buf)?;
+        let bytes = &buf.remaining()[..len];
+        let mut value = 0i64;
+        let mut shift = 0;
+
+        for &b in bytes {
+            // The last possible byte only holds the sign bit, the remaining
+            // bits have to be its sign extension
+            ensure!(
+                shift < 63 || b == 0x00 || b == 0x7F,
+                VarIntOverflowSnafu { bits: 64u32 }
+            );
+
+            value |= i64::from(b & 0x7F) << shift;
+            shift += 7;
+        }
+
+        // A final byte which only repeats the sign of the previous byte is
+        // redundant
+        let last = bytes[len - 1];
+        if len > 1 {
+            let sign = bytes[len - 2] & 0x40 != 0;
+            ensure!(
+                !(last == 0x00 && !sign || last == 0x7F && sign),
+                OverlongVarIntSnafu
+            );
+        }
+
+        if shift < 64 && last & 0x40 != 0 {
+            value |= -1 << shift;
+        }
+
+        buf.skipn(len)?;
+        Ok(Self(value))
+    }
+
+    fn encode(&self, buf: &mut Writer) -> write::Result {
+        let mut b = [0; MAX_LEB128_LEN];
+        let mut value = self.0;
+        let mut len = 0;
+
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+
+            let done = value == 0 && byte & 0x40 == 0 || value == -1 && byte & 0x40 != 0;
+            if done {
+                b[len] = byte;
+                len += 1;
+                break;
+            }
+
+            b[len] = byte | 0x80;
+            len += 1;
+        }
+
+        buf.try_write(&b[..len])
+    }
+}
+
+impl ZigZag {
+    fn decode(buf: &mut Reader) -> read::Result<Self> {
+        let value = Uleb128::decode(buf)?.0;
+        Ok(Self((value >> 1) as i64 ^ -((value & 1) as i64)))
+    }
+
+    fn encode(&self, buf: &mut Writer) -> write::Result {
+        let value = ((self.0 << 1) ^ (self.0 >> 63)) as u64;
+        Uleb128(value).encode(buf)
+    }
+}
+
+impl QuicVarInt {
+    /// Reads a QUIC variable-length integer like [`Read`], but rejects
+    /// encodings which use more bytes than necessary with the error
+    /// [`read::Error::OverlongVarInt`]. RFC 9000 allows such encodings, this
+    /// is meant for protocols which require the minimal encoding.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{read::Error, varint::QuicVarInt, BigEndian, Read as _, Reader};
+    ///
+    /// let d = &[0x40, 0x25];
+    ///
+    /// let mut b = Reader::new(d);
+    /// assert_eq!(QuicVarInt::read::<BigEndian>(&mut b), Ok(QuicVarInt(37)));
+    ///
+    /// let mut b = Reader::new(d);
+    /// assert_eq!(QuicVarInt::read_strict(&mut b), Err(Error::OverlongVarInt));
+    /// ```
+    pub fn read_strict(buf: &mut Reader) -> read::Result<Self> {
+        let (value, len) = Self::decode_len(buf)?;
+        ensure!(value_len_quic(value) == len, OverlongVarIntSnafu);
+
+        buf.skipn(len)?;
+        Ok(Self(value))
+    }
+
+    fn decode(buf: &mut Reader) -> read::Result<Self> {
+        let (value, len) = Self::decode_len(buf)?;
+
+        buf.skipn(len)?;
+        Ok(Self(value))
+    }
+
+    /// Returns the value and encoded length of the integer at the cursor
+    /// without consuming any bytes.
+    fn decode_len(buf: &mut Reader) -> read::Result<(u64, usize)> {
+        buf.fill(1)?;
+
+        let len = 1 << (buf.remaining()[0] >> 6);
+        buf.fill(len)?;
+
+        let bytes = &buf.remaining()[..len];
+        let value = bytes[1..]
+            .iter()
+            .fold(u64::from(bytes[0] & 0x3F), |v, &b| v << 8 | u64::from(b));
+
+        Ok((value, len))
+    }
+
+    fn encode(&self, buf: &mut Writer) -> write::Result {
+        ensure!(
+            self.0 <= MAX_QUIC_VALUE,
+            WriteVarIntOverflowSnafu { value: self.0 }
+        );
+
+        let len = value_len_quic(self.0);
+        let prefix = (len.trailing_zeros() as u64) << (len * 8 - 2);
+        let b = (self.0 | prefix).to_be_bytes();
+
+        buf.try_write(&b[8 - len..])
+    }
+}
+
+/// Returns the length of the LEB128 encoded integer at the cursor without
+/// consuming any bytes. Streaming readers read more bytes until the end of
+/// the integer is buffered.
+fn leb128_len(buf: &mut Reader) -> read::Result<usize> {
+    for len in 1..=MAX_LEB128_LEN {
+        buf.fill(len)?;
+
+        if buf.remaining()[len - 1] & 0x80 == 0 {
+            return Ok(len);
+        }
+    }
+
+    VarIntOverflowSnafu { bits: 64u32 }.fail()
+}
+
+fn decode_uleb128(bytes: &[u8]) -> read::Result<u64> {
+    let mut value = 0u64;
+
+    for (i, &b) in bytes.iter().enumerate() {
+        let shift = i * 7;
+
+        // The last possible byte can only hold the most significant bit
+        ensure!(shift < 63 || b <= 1, VarIntOverflowSnafu { bits: 64u32 });
+        value |= u64::from(b & 0x7F) << shift;
+    }
+
+    ensure!(
+        bytes.len() == 1 || bytes[bytes.len() - 1] != 0,
+        OverlongVarIntSnafu
+    );
+
+    Ok(value)
+}
+
+fn encode_uleb128(mut value: u64, b: &mut [u8; MAX_LEB128_LEN]) -> usize {
+    let mut len = 0;
+
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            b[len] = byte;
+            return len + 1;
+        }
+
+        b[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+/// Returns the minimal number of bytes needed to encode `value` as QUIC
+/// varint.
+fn value_len_quic(value: u64) -> usize {
+    match value {
+        0..=0x3F => 1,
+        0x40..=0x3FFF => 2,
+        0x4000..=0x3FFF_FFFF => 4,
+        _ => 8,
+    }
+}
@@ -1,11 +1,28 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt, io, marker::PhantomData};
 
 use snafu::{ensure, Snafu};
 
-use crate::Endianness;
+use crate::{
+    string::{StringPolicy, StringPrefix},
+    BitOrder, Endianness, SupportedEndianness,
+};
 
 pub type Result<T = usize, E = WriteError> = std::result::Result<T, E>;
 
+/// The maximum length of a domain name in wire format, including all length
+/// octets and the terminating null label (RFC 1035, section 2.3.4).
+const MAX_DOMAIN_NAME_LEN: usize = 255;
+
+/// The maximum length of a single label of a domain name.
+const MAX_LABEL_LEN: usize = 63;
+
+/// Compression pointers use 14 bits to encode the offset.
+const MAX_POINTER_OFFSET: usize = 0x3FFF;
+
+/// The maximum encoded length of a length field. This fits all integers up
+/// to 128 bits and variable-length integers holding a `usize`.
+const MAX_LENGTH_FIELD_LEN: usize = 16;
+
 #[derive(Debug, PartialEq, Snafu)]
 pub enum WriteError {
     #[snafu(display(
@@ -19,17 +36,296 @@ pub enum WriteError {
     #[snafu(display("non-ascii string data cannot be written"))]
     NonAsciiData,
 
+    #[snafu(display("cannot write {nbits} bits, at most 64 bits are supported"))]
+    BitCountOverflow {
+        nbits: u32,
+    },
+
+    #[snafu(display("the value {value} cannot be encoded using {nbits} bits"))]
+    BitValueOverflow {
+        value: u64,
+        nbits: u32,
+    },
+
+    #[snafu(display("the last byte is incomplete, {nbits} bits are missing"))]
+    UnalignedBits {
+        nbits: u32,
+    },
+
+    #[snafu(display("invalid label length {len}, labels must be between 1 and 63 bytes long"))]
+    InvalidLabelLength {
+        len: usize,
+    },
+
+    #[snafu(display("domain name exceeds the maximum length of 255 bytes"))]
+    NameTooLong,
+
+    #[snafu(display("the length {len} overflows the reserved length field"))]
+    LengthOverflow {
+        len: usize,
+    },
+
+    #[snafu(display("the length slot doesn't belong to the current span"))]
+    InvalidLengthSlot,
+
+    /// This error indicates that a fixed size buffer has not enough space
+    /// left to write the requested amount of bytes.
+    #[snafu(display("buffer full, cannot write {nbytes} bytes with {remaining} bytes left"))]
+    BufferFull {
+        nbytes: usize,
+        remaining: usize,
+    },
+
+    /// This error indicates that writing to the underlying sink of a
+    /// [`Writer`] failed.
+    #[snafu(display("i/o error: {message}"))]
+    Io {
+        kind: io::ErrorKind,
+        message: String,
+    },
+
+    #[snafu(display("length fields cannot be patched when writing to a sink"))]
+    PatchUnsupported,
+
+    /// This error indicates that a NUL-terminated string contains a NUL byte
+    /// before its end.
+    #[snafu(display("string contains a NUL byte at offset {offset}"))]
+    InteriorNul {
+        offset: usize,
+    },
+
+    /// This error indicates that a value is too large to be encoded as a
+    /// variable-length integer.
+    #[snafu(visibility(pub(crate)))]
+    #[snafu(display("the value {value} cannot be encoded as a variable-length integer"))]
+    VarIntOverflow {
+        value: u64,
+    },
+
+    /// This error indicates that a NaN with a non-canonical bit pattern was
+    /// written while [`FloatPolicy::Reject`] is active.
+    #[snafu(display("non-canonical NaN cannot be written"))]
+    NonCanonicalNan,
+
+    /// This error indicates that a negative zero was written while
+    /// [`FloatPolicy::Reject`] is active.
+    #[snafu(display("negative zero cannot be written"))]
+    NegativeZero,
+
     LittleEndianNotSupported,
     BigEndianNotSupported,
 }
 
+impl From<io::Error> for WriteError {
+    fn from(err: io::Error) -> Self {
+        IoSnafu {
+            kind: err.kind(),
+            message: err.to_string(),
+        }
+        .build()
+    }
+}
+
 #[derive(Debug, Default)]
-pub struct Writer {
+pub struct Writer<'a> {
     spans: Vec<usize>,
-    buf: Vec<u8>,
+    buf: Storage<'a>,
+    error: Option<WriteError>,
+    names: Option<NameTable>,
+    floats: FloatPolicy,
+    strings: StringPolicy,
+    string_prefix: StringPrefix,
+}
+
+/// The storage bytes are written to by a [`Writer`].
+enum Storage<'a> {
+    /// A growable vector owned by the writer.
+    Owned(Vec<u8>),
+
+    /// A caller-provided buffer of fixed size. `len` is the number of bytes
+    /// already written to the buffer.
+    Fixed { buf: &'a mut [u8], len: usize },
+
+    /// A sink bytes are directly forwarded to. `len` is the number of bytes
+    /// already written to the sink.
+    Sink {
+        inner: Box<dyn io::Write + 'a>,
+        len: usize,
+    },
+}
+
+impl fmt::Debug for Storage<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Owned(buf) => f.debug_tuple("Owned").field(buf).finish(),
+            Self::Fixed { buf, len } => f
+                .debug_struct("Fixed")
+                .field("buf", buf)
+                .field("len", len)
+                .finish(),
+            Self::Sink { len, .. } => f
+                .debug_struct("Sink")
+                .field("len", len)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+impl Default for Storage<'_> {
+    fn default() -> Self {
+        Self::Owned(Vec::new())
+    }
+}
+
+impl Storage<'_> {
+    fn extend(&mut self, bytes: &[u8]) -> Result<()> {
+        match self {
+            Self::Owned(buf) => buf.extend_from_slice(bytes),
+            Self::Fixed { buf, len } => {
+                let remaining = buf.len() - *len;
+                ensure!(
+                    bytes.len() <= remaining,
+                    BufferFullSnafu {
+                        nbytes: bytes.len(),
+                        remaining
+                    }
+                );
+
+                buf[*len..*len + bytes.len()].copy_from_slice(bytes);
+                *len += bytes.len();
+            }
+            Self::Sink { inner, len } => {
+                inner.write_all(bytes)?;
+                *len += bytes.len();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clear(&mut self) {
+        match self {
+            Self::Owned(buf) => buf.clear(),
+            Self::Fixed { len, .. } | Self::Sink { len, .. } => *len = 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Owned(buf) => buf.len(),
+            Self::Fixed { len, .. } | Self::Sink { len, .. } => *len,
+        }
+    }
+
+    fn bytes(&self) -> &[u8] {
+        match self {
+            Self::Owned(buf) => buf,
+            Self::Fixed { buf, len } => &buf[..*len],
+            Self::Sink { .. } => &[],
+        }
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        match self {
+            Self::Owned(buf) => buf,
+            Self::Fixed { buf, len } => &mut buf[..*len],
+            Self::Sink { .. } => &mut [],
+        }
+    }
 }
 
-impl Writer {
+/// Controls how floating point values with multiple possible encodings are
+/// written. NaN values can carry an arbitrary payload and a sign bit, and
+/// zero can be either positive or negative. Values which compare equal can
+/// thus have different encodings. [`FloatPolicy::Canonicalize`] and
+/// [`FloatPolicy::Reject`] ensure that encodings can be compared byte for
+/// byte.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FloatPolicy {
+    /// Values are written as is, including NaN payloads and negative zero.
+    #[default]
+    Preserve,
+
+    /// All NaN values are written as the canonical quiet NaN (`0x7FC00000`
+    /// for `f32` and `0x7FF8000000000000` for `f64`) and negative zero is
+    /// written as positive zero.
+    Canonicalize,
+
+    /// Writing a NaN value other than the canonical quiet NaN returns the
+    /// error [`WriteError::NonCanonicalNan`]. Writing negative zero returns
+    /// the error [`WriteError::NegativeZero`].
+    Reject,
+}
+
+impl FloatPolicy {
+    /// Applies the policy to `value` and returns the value to write.
+    pub(crate) fn apply<F: Float>(self, value: F) -> Result<F> {
+        match self {
+            Self::Preserve => Ok(value),
+            Self::Canonicalize if value.is_nan() => Ok(F::canonical_nan()),
+            Self::Canonicalize if value.is_negative_zero() => Ok(F::ZERO),
+            Self::Canonicalize => Ok(value),
+            Self::Reject => {
+                ensure!(
+                    !value.is_nan() || value.is_canonical_nan(),
+                    NonCanonicalNanSnafu
+                );
+                ensure!(!value.is_negative_zero(), NegativeZeroSnafu);
+                Ok(value)
+            }
+        }
+    }
+}
+
+/// Floating point types which can be written according to a [`FloatPolicy`].
+pub(crate) trait Float: Copy {
+    const ZERO: Self;
+
+    /// Returns the canonical quiet NaN. The bit pattern of the `NAN`
+    /// constants is not guaranteed, which is why it is built from its bits.
+    fn canonical_nan() -> Self;
+    fn is_nan(self) -> bool;
+    fn is_negative_zero(self) -> bool;
+    fn is_canonical_nan(self) -> bool;
+}
+
+macro_rules! float_impl {
+    ($SelfT:ty, $NanBits:expr) => {
+        impl Float for $SelfT {
+            const ZERO: Self = 0.0;
+
+            fn canonical_nan() -> Self {
+                <$SelfT>::from_bits($NanBits)
+            }
+
+            fn is_nan(self) -> bool {
+                <$SelfT>::is_nan(self)
+            }
+
+            fn is_negative_zero(self) -> bool {
+                self == 0.0 && self.is_sign_negative()
+            }
+
+            fn is_canonical_nan(self) -> bool {
+                self.to_bits() == $NanBits
+            }
+        }
+    };
+}
+
+float_impl!(f32, 0x7FC0_0000);
+float_impl!(f64, 0x7FF8_0000_0000_0000);
+
+/// Maps label suffixes of domain names to the offset they were written at.
+/// The offsets are relative to `base`, which marks the start of the current
+/// message.
+#[derive(Debug, Default)]
+struct NameTable {
+    suffixes: HashMap<Vec<u8>, u16>,
+    base: usize,
+}
+
+impl<'a> Writer<'a> {
     /// Creates a new empty [`Buffer`] backed by a `Vec<u8>`.
     ///
     /// ### Example
@@ -60,17 +356,94 @@ impl Writer {
     /// assert_eq!(b.bytes(), &[69, 88]);
     /// ```
     pub fn new_with<T: AsRef<[u8]>>(b: T) -> Self {
-        let b = b.as_ref();
-        let mut buf = Self {
-            buf: Vec::with_capacity(b.len()),
+        Self {
+            buf: Storage::Owned(b.as_ref().to_vec()),
+            spans: Vec::new(),
+            error: None,
+            names: None,
+            floats: FloatPolicy::default(),
+            strings: StringPolicy::default(),
+            string_prefix: StringPrefix::default(),
+        }
+    }
+
+    /// Creates a new [`Writer`] which writes into the caller-provided buffer
+    /// `buf` instead of a growable `Vec<u8>`. Writing more bytes than the
+    /// buffer can hold returns the error [`WriteError::BufferFull`]. In this
+    /// case none of the requested bytes are written.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{write::WriteError, BigEndian, Write as _, Writer};
+    ///
+    /// let mut packet = [0u8; 4];
+    /// let mut b = Writer::new_fixed(&mut packet);
+    ///
+    /// 17752u16.write::<BigEndian>(&mut b).unwrap();
+    /// assert_eq!(b.bytes(), &[69, 88]);
+    /// assert_eq!(b.remaining(), Some(2));
+    ///
+    /// assert_eq!(
+    ///     1163411789u32.write::<BigEndian>(&mut b),
+    ///     Err(WriteError::BufferFull { nbytes: 4, remaining: 2 })
+    /// );
+    /// ```
+    pub fn new_fixed(buf: &'a mut [u8]) -> Self {
+        Self {
+            buf: Storage::Fixed { buf, len: 0 },
             spans: Vec::new(),
-        };
+            error: None,
+            names: None,
+            floats: FloatPolicy::default(),
+            strings: StringPolicy::default(),
+            string_prefix: StringPrefix::default(),
+        }
+    }
 
-        buf.write(b);
-        buf
+    /// Creates a new [`Writer`] which forwards all bytes directly to the sink
+    /// `inner`, like a socket, a file or a `BufWriter`, without buffering
+    /// them in an intermediate `Vec<u8>`. Errors of the sink are returned as
+    /// [`WriteError::Io`]. [`Writer::len()`] returns the number of bytes
+    /// written to the sink, which is also used by spans and domain name
+    /// compression.
+    ///
+    /// As written bytes are not retained, [`Writer::bytes()`] always returns
+    /// an empty slice and length fields cannot be patched, in which case
+    /// [`Writer::reserve_length()`] returns [`WriteError::PatchUnsupported`].
+    /// Use [`Writer::flush()`] to flush the sink.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{BigEndian, Write as _, Writer};
+    ///
+    /// let mut sink = Vec::new();
+    /// let mut b = Writer::new_sink(&mut sink);
+    ///
+    /// assert_eq!(17752u16.write::<BigEndian>(&mut b), Ok(2));
+    /// assert_eq!(b.len(), 2);
+    ///
+    /// drop(b);
+    /// assert_eq!(sink, vec![69, 88]);
+    /// ```
+    pub fn new_sink(inner: impl io::Write + 'a) -> Self {
+        Self {
+            buf: Storage::Sink {
+                inner: Box::new(inner),
+                len: 0,
+            },
+            spans: Vec::new(),
+            error: None,
+            names: None,
+            floats: FloatPolicy::default(),
+            strings: StringPolicy::default(),
+            string_prefix: StringPrefix::default(),
+        }
     }
 
-    /// Adds a new byte to the end of the [`Buffer`].
+    /// Adds a new byte to the end of the [`Buffer`]. See [`Writer::write()`]
+    /// for writers backed by a fixed size buffer or a sink.
     ///
     /// ### Example
     ///
@@ -84,14 +457,20 @@ impl Writer {
     /// assert_eq!(b.bytes(), &[69]);
     /// ```
     pub fn push(&mut self, b: u8) {
-        self.buf.push(b);
+        self.write([b]);
+    }
 
-        if let Some(last) = self.spans.last_mut() {
-            *last += 1;
-        }
+    /// Adds a new byte to the end of the [`Writer`]. Unlike
+    /// [`Writer::push()`], errors are returned directly, see
+    /// [`Writer::try_write()`].
+    pub fn try_push(&mut self, b: u8) -> Result<()> {
+        self.try_write([b])?;
+        Ok(())
     }
 
-    /// Clears the [`Buffer`], removing all bytes.
+    /// Clears the [`Buffer`], removing all bytes. Writers backed by a sink
+    /// only reset the number of written bytes. A pending error, see
+    /// [`Writer::take_error()`], is discarded.
     ///
     /// ### Example
     ///
@@ -104,7 +483,9 @@ impl Writer {
     /// assert_eq!(b.len(), 0);
     /// ```
     pub fn clear(&mut self) {
-        self.buf.clear()
+        self.buf.clear();
+        self.error = None;
+        self.reset_compression();
     }
 
     /// Returns the length of the [`Buffer`].
@@ -121,6 +502,26 @@ impl Writer {
         self.buf.len()
     }
 
+    /// Returns the number of bytes which can still be written if the
+    /// [`Writer`] is backed by a fixed size buffer. Returns [`None`] if the
+    /// [`Writer`] is backed by a growable `Vec<u8>` or a sink.
+    pub fn remaining(&self) -> Option<usize> {
+        match &self.buf {
+            Storage::Owned(_) | Storage::Sink { .. } => None,
+            Storage::Fixed { buf, len } => Some(buf.len() - len),
+        }
+    }
+
+    /// Flushes the sink if the [`Writer`] is backed by one. This is a no-op
+    /// otherwise.
+    pub fn flush(&mut self) -> Result<()> {
+        if let Storage::Sink { inner, .. } = &mut self.buf {
+            inner.flush()?;
+        }
+
+        Ok(())
+    }
+
     /// Returns if the [`Buffer`] is empty.
     ///
     /// ### Example
@@ -135,12 +536,22 @@ impl Writer {
     /// assert_eq!(b.is_empty(), false);
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.buf.is_empty()
+        self.len() == 0
     }
 
     /// Writes multiple bytes of data to the [`Buffer`]. Possible
     /// parameters are: `Vec<u8>`, `&[u8]`, and `[u8]`.
     ///
+    /// Writing to a growable `Vec<u8>` never fails. If the [`Writer`] is
+    /// backed by a fixed size buffer which cannot hold all bytes, no bytes
+    /// are written. If it is backed by a sink which returns an error, some
+    /// bytes may already have been written to the sink. In both cases `0` is
+    /// returned and the error is kept until it is retrieved with
+    /// [`Writer::take_error()`].
+    /// [`Write::write()`] returns a pending error after the value is written,
+    /// so [`Write`] implementations can use this method unchanged. Use
+    /// [`Writer::try_write()`] to handle errors directly.
+    ///
     /// ### Example
     ///
     /// ```
@@ -153,16 +564,74 @@ impl Writer {
     /// assert_eq!(b.bytes(), &[69, 88, 65]);
     /// ```
     pub fn write(&mut self, b: impl AsRef<[u8]>) -> usize {
+        match self.try_write(b) {
+            Ok(n) => n,
+            Err(err) => {
+                self.error.get_or_insert(err);
+                0
+            }
+        }
+    }
+
+    /// Writes multiple bytes of data to the [`Writer`], like
+    /// [`Writer::write()`], but returns errors directly. If the [`Writer`] is
+    /// backed by a fixed size buffer which cannot hold all bytes, the error
+    /// [`WriteError::BufferFull`] is returned and no bytes are written. Sinks
+    /// may have written some bytes before returning an error. A pending error
+    /// of a previous write is returned before writing anything.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{write::WriteError, Writer};
+    ///
+    /// let mut packet = [0u8; 2];
+    /// let mut b = Writer::new_fixed(&mut packet);
+    ///
+    /// assert_eq!(b.try_write([69, 88]), Ok(2));
+    /// assert_eq!(
+    ///     b.try_write([65]),
+    ///     Err(WriteError::BufferFull { nbytes: 1, remaining: 0 })
+    /// );
+    /// ```
+    pub fn try_write(&mut self, b: impl AsRef<[u8]>) -> Result {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
         let bytes = b.as_ref();
         let len = bytes.len();
 
-        self.buf.extend_from_slice(bytes);
+        self.buf.extend(bytes)?;
 
         if let Some(last) = self.spans.last_mut() {
             *last += len;
         }
 
-        len
+        Ok(len)
+    }
+
+    /// Returns and clears the error of a previous [`Writer::write()`] or
+    /// [`Writer::push()`] which failed. Returns [`None`] if all writes
+    /// succeeded. Writers backed by a growable `Vec<u8>` never fail.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{write::WriteError, Writer};
+    ///
+    /// let mut packet = [0u8; 1];
+    /// let mut b = Writer::new_fixed(&mut packet);
+    ///
+    /// assert_eq!(b.write([69, 88]), 0);
+    /// assert_eq!(
+    ///     b.take_error(),
+    ///     Some(WriteError::BufferFull { nbytes: 2, remaining: 1 })
+    /// );
+    /// assert_eq!(b.take_error(), None);
+    /// ```
+    pub fn take_error(&mut self) -> Option<WriteError> {
+        self.error.take()
     }
 
     /// Writes a character string to the [`Buffer`]. This will first write the
@@ -178,7 +647,8 @@ impl Writer {
     /// [`u8::MAX`][u8max]. Most network protocols however only allow character
     /// strings with a max size of [`u8::MAX`][u8max] at most. DNS being one
     /// prominent example. The length label is currently **only** writen using
-    /// big endian byte order.
+    /// big endian byte order. Use [`Writer::write_length_prefixed()`] to
+    /// write byte strings with wider or little endian length prefixes.
     ///
     /// [u8max]: https://doc.rust-lang.org/std/primitive.u8.html#associatedconstant.MAX
     ///
@@ -207,8 +677,494 @@ impl Writer {
             ensure!(len <= max_len, MaxLengthOverflowSnafu);
         }
 
+        self.ensure_remaining(s.len() + 1)?;
+
         let n = len.write_be(self)?;
-        Ok(self.write(s) + n)
+        Ok(self.try_write(s)? + n)
+    }
+
+    /// Writes a byte string prefixed with its length. The length prefix is of
+    /// type `L` and written with the endianness `E`. Any type which can be
+    /// created from a `usize` can be used as the prefix, like `u8`, `u16`,
+    /// `u32` or the variable-length integers [`Uleb128`] and [`QuicVarInt`].
+    /// Returns the number of bytes written, including the prefix.
+    ///
+    /// If the length exceeds the optional `max_len`, the error
+    /// [`WriteError::MaxLengthOverflow`] is returned. If the length doesn't
+    /// fit into `L`, the error [`WriteError::LengthOverflow`] is returned.
+    /// Fixed size buffers which cannot hold the whole string return the error
+    /// [`WriteError::BufferFull`] without writing the prefix.
+    ///
+    /// [`Uleb128`]: crate::varint::Uleb128
+    /// [`QuicVarInt`]: crate::varint::QuicVarInt
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{varint::Uleb128, write::WriteError, BigEndian, LittleEndian, Writer};
+    ///
+    /// let mut b = Writer::new();
+    ///
+    /// assert_eq!(b.write_length_prefixed::<u16, BigEndian>(&[88, 65], None), Ok(4));
+    /// assert_eq!(b.write_length_prefixed::<u32, LittleEndian>(&[77], Some(1)), Ok(5));
+    /// assert_eq!(b.write_length_prefixed::<Uleb128, BigEndian>(&[80], None), Ok(2));
+    /// assert_eq!(b.bytes(), &[0, 2, 88, 65, 1, 0, 0, 0, 77, 1, 80]);
+    ///
+    /// assert_eq!(
+    ///     b.write_length_prefixed::<u8, BigEndian>(&[0; 256], None),
+    ///     Err(WriteError::LengthOverflow { len: 256 })
+    /// );
+    /// ```
+    pub fn write_length_prefixed<L, E>(
+        &mut self,
+        s: impl AsRef<[u8]>,
+        max_len: Option<usize>,
+    ) -> Result
+    where
+        L: Write + TryFrom<usize>,
+        E: Endianness,
+    {
+        let s = s.as_ref();
+        let len = s.len();
+
+        if let Some(max_len) = max_len {
+            ensure!(len <= max_len, MaxLengthOverflowSnafu);
+        }
+
+        let prefix = L::try_from(len).map_err(|_| LengthOverflowSnafu { len }.build())?;
+
+        // Encode the prefix upfront to make sure fixed size buffers can hold
+        // the whole string before anything is written
+        let mut field = [0; MAX_LENGTH_FIELD_LEN];
+        let field = encode_length_field::<L, E>(prefix, &mut field)?;
+
+        self.ensure_remaining(field.len() + len)?;
+
+        let n = self.try_write(field)?;
+        Ok(self.try_write(s)? + n)
+    }
+
+    /// Writes the string `s` without a length prefix. With
+    /// [`StringPolicy::Ascii`], non-ASCII strings are rejected with the error
+    /// [`WriteError::NonAsciiData`]. Otherwise, the UTF-8 bytes of the string
+    /// are written unchanged.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{string::StringPolicy, write::WriteError, Writer};
+    ///
+    /// let mut b = Writer::new();
+    ///
+    /// assert_eq!(
+    ///     b.write_str("grüß", StringPolicy::Ascii),
+    ///     Err(WriteError::NonAsciiData)
+    /// );
+    /// assert_eq!(b.write_str("grüß", StringPolicy::Utf8), Ok(6));
+    /// ```
+    pub fn write_str(&mut self, s: &str, policy: StringPolicy) -> Result {
+        ensure!(
+            policy != StringPolicy::Ascii || s.is_ascii(),
+            NonAsciiDataSnafu
+        );
+
+        self.try_write(s)
+    }
+
+    /// Writes the string `s` prefixed with its length, see
+    /// [`Writer::write_length_prefixed()`]. The string is validated according
+    /// to `policy`, see [`Writer::write_str()`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{string::StringPolicy, BigEndian, Writer};
+    ///
+    /// let mut b = Writer::new();
+    ///
+    /// assert_eq!(
+    ///     b.write_str_prefixed::<u8, BigEndian>("ssh", None, StringPolicy::Ascii),
+    ///     Ok(4)
+    /// );
+    /// assert_eq!(b.bytes(), &[3, 115, 115, 104]);
+    /// ```
+    pub fn write_str_prefixed<L, E>(
+        &mut self,
+        s: &str,
+        max_len: Option<usize>,
+        policy: StringPolicy,
+    ) -> Result
+    where
+        L: Write + TryFrom<usize>,
+        E: Endianness,
+    {
+        ensure!(
+            policy != StringPolicy::Ascii || s.is_ascii(),
+            NonAsciiDataSnafu
+        );
+
+        self.write_length_prefixed::<L, E>(s, max_len)
+    }
+
+    /// Writes a NUL-terminated string (C string). The string can be at most
+    /// `max_len` bytes long, excluding the terminator. Returns the number of
+    /// bytes written, including the terminator.
+    ///
+    /// If the string contains a NUL byte, the error
+    /// [`WriteError::InteriorNul`] is returned. If the length exceeds the
+    /// optional `max_len`, the error [`WriteError::MaxLengthOverflow`] is
+    /// returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{write::WriteError, Writer};
+    ///
+    /// let mut b = Writer::new();
+    ///
+    /// assert_eq!(b.write_cstring("boot", None), Ok(5));
+    /// assert_eq!(b.bytes(), b"boot\0");
+    ///
+    /// assert_eq!(
+    ///     b.write_cstring("bo\0ot", None),
+    ///     Err(WriteError::InteriorNul { offset: 2 })
+    /// );
+    /// ```
+    pub fn write_cstring(&mut self, s: impl AsRef<[u8]>, max_len: Option<usize>) -> Result {
+        let s = s.as_ref();
+
+        if let Some(offset) = s.iter().position(|b| *b == 0) {
+            return InteriorNulSnafu { offset }.fail();
+        }
+
+        if let Some(max_len) = max_len {
+            ensure!(s.len() <= max_len, MaxLengthOverflowSnafu);
+        }
+
+        self.ensure_remaining(s.len() + 1)?;
+
+        let n = self.try_write(s)?;
+        self.try_push(0)?;
+
+        Ok(n + 1)
+    }
+
+    /// Writes `s` into a fixed-width field of `width` bytes. The remaining
+    /// bytes of the field are filled with `pad`, like NUL or space padded
+    /// fields in tar headers or the DHCP `sname` and `file` fields. Strings
+    /// which are exactly `width` bytes long are written without padding.
+    /// Returns `width`.
+    ///
+    /// If `s` is longer than `width`, the error
+    /// [`WriteError::MaxLengthOverflow`] is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::Writer;
+    ///
+    /// let mut b = Writer::new();
+    ///
+    /// assert_eq!(b.write_padded("tftp", 6, 0), Ok(6));
+    /// assert_eq!(b.write_padded("root", 6, b' '), Ok(6));
+    /// assert_eq!(b.bytes(), b"tftp\0\0root  ");
+    /// ```
+    pub fn write_padded(&mut self, s: impl AsRef<[u8]>, width: usize, pad: u8) -> Result {
+        let s = s.as_ref();
+
+        ensure!(s.len() <= width, MaxLengthOverflowSnafu);
+        self.ensure_remaining(width)?;
+
+        self.try_write(s)?;
+        for _ in s.len()..width {
+            self.try_push(pad)?;
+        }
+
+        Ok(width)
+    }
+
+    /// Ensures that a fixed size buffer can hold `nbytes` more bytes. This
+    /// is used to keep multi-part writes atomic.
+    fn ensure_remaining(&self, nbytes: usize) -> Result<()> {
+        if let Some(remaining) = self.remaining() {
+            ensure!(nbytes <= remaining, BufferFullSnafu { nbytes, remaining });
+        }
+
+        Ok(())
+    }
+
+    /// Enables domain name compression for [`Writer::write_domain_name()`].
+    /// The current position marks the start of the message, which means that
+    /// compression pointers are relative to this position. Any previously
+    /// stored label suffixes are discarded.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::Writer;
+    ///
+    /// let mut b = Writer::new();
+    /// b.enable_compression();
+    ///
+    /// b.write_domain_name(&["example", "com"]).unwrap();
+    /// b.write_domain_name(&["www", "example", "com"]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     b.bytes(),
+    ///     b"\x07example\x03com\x00\x03www\xC0\x00"
+    /// );
+    /// ```
+    pub fn enable_compression(&mut self) {
+        self.names = Some(NameTable {
+            suffixes: HashMap::new(),
+            base: self.len(),
+        });
+    }
+
+    /// Disables domain name compression and discards all stored label
+    /// suffixes.
+    pub fn disable_compression(&mut self) {
+        self.names = None;
+    }
+
+    /// Returns if domain name compression is enabled.
+    pub fn compression_enabled(&self) -> bool {
+        self.names.is_some()
+    }
+
+    /// Discards all stored label suffixes and marks the current position as
+    /// the start of a new message. This should be called before writing each
+    /// message when multiple messages are written using the same [`Writer`].
+    /// This is a no-op if compression is disabled.
+    pub fn reset_compression(&mut self) {
+        let len = self.len();
+
+        if let Some(names) = &mut self.names {
+            names.suffixes.clear();
+            names.base = len;
+        }
+    }
+
+    /// Sets the [`FloatPolicy`] used when writing `f32` and `f64` values. The
+    /// default policy is [`FloatPolicy::Preserve`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{write::{FloatPolicy, WriteError}, BigEndian, Write as _, Writer};
+    ///
+    /// let mut b = Writer::new();
+    /// b.set_float_policy(FloatPolicy::Canonicalize);
+    ///
+    /// (-0.0f32).write::<BigEndian>(&mut b).unwrap();
+    /// f32::from_bits(0xFFC00001).write::<BigEndian>(&mut b).unwrap();
+    /// assert_eq!(b.bytes(), &[0, 0, 0, 0, 0x7F, 0xC0, 0, 0]);
+    ///
+    /// b.set_float_policy(FloatPolicy::Reject);
+    /// assert_eq!(
+    ///     (-0.0f32).write::<BigEndian>(&mut b),
+    ///     Err(WriteError::NegativeZero)
+    /// );
+    /// ```
+    pub fn set_float_policy(&mut self, policy: FloatPolicy) {
+        self.floats = policy;
+    }
+
+    /// Returns the [`FloatPolicy`] used when writing `f32` and `f64` values.
+    pub fn float_policy(&self) -> FloatPolicy {
+        self.floats
+    }
+
+    /// Sets the [`StringPolicy`] used when writing [`String`]s and `&str`s.
+    /// The default policy is [`StringPolicy::Ascii`].
+    pub fn set_string_policy(&mut self, policy: StringPolicy) {
+        self.strings = policy;
+    }
+
+    /// Returns the [`StringPolicy`] used when writing [`String`]s and
+    /// `&str`s.
+    pub fn string_policy(&self) -> StringPolicy {
+        self.strings
+    }
+
+    /// Sets the [`StringPrefix`] used when writing [`String`]s and `&str`s.
+    /// The default is [`StringPrefix::None`].
+    pub fn set_string_prefix(&mut self, prefix: StringPrefix) {
+        self.string_prefix = prefix;
+    }
+
+    /// Returns the [`StringPrefix`] used when writing [`String`]s and
+    /// `&str`s.
+    pub fn string_prefix(&self) -> StringPrefix {
+        self.string_prefix
+    }
+
+    /// Writes a domain name in DNS wire format. Each label is written as a
+    /// length-prefixed byte sequence, followed by the terminating null label.
+    ///
+    /// If compression is enabled via [`Writer::enable_compression()`], the
+    /// offset of each written label suffix is remembered. When a suffix was
+    /// already written as part of a previous name, a 14-bit compression
+    /// pointer to it is written instead (RFC 1035, section 4.1.4). Suffixes
+    /// are matched byte for byte.
+    ///
+    /// Labels must be between 1 and 63 bytes long, otherwise the error
+    /// [`WriteError::InvalidLabelLength`] is returned. If the name exceeds
+    /// 255 bytes, the error [`WriteError::NameTooLong`] is returned. Nothing
+    /// is written in both cases. Fixed size buffers have to hold the name
+    /// without compression, otherwise [`WriteError::BufferFull`] is returned
+    /// and nothing is written.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::Writer;
+    ///
+    /// let mut b = Writer::new();
+    /// let n = b.write_domain_name(&["example", "com"]).unwrap();
+    ///
+    /// assert_eq!(n, 13);
+    /// assert_eq!(b.bytes(), b"\x07example\x03com\x00");
+    /// ```
+    pub fn write_domain_name<L: AsRef<[u8]>>(&mut self, labels: &[L]) -> Result {
+        let mut len = 1;
+
+        for label in labels {
+            let label_len = label.as_ref().len();
+
+            ensure!(
+                (1..=MAX_LABEL_LEN).contains(&label_len),
+                InvalidLabelLengthSnafu { len: label_len }
+            );
+
+            len += label_len + 1;
+        }
+
+        ensure!(len <= MAX_DOMAIN_NAME_LEN, NameTooLongSnafu);
+
+        // Compression only shortens the name, so this keeps the write atomic
+        self.ensure_remaining(len)?;
+
+        self.enter();
+
+        // The span has to be exited on every return path, otherwise the
+        // length of enclosing spans is off
+        let result = self.write_labels(labels);
+        let n = self.exit();
+
+        result.map(|_| n)
+    }
+
+    /// Writes the labels of a domain name, see [`Writer::write_domain_name()`].
+    /// Label suffixes are only added to the compression table once the whole
+    /// name was written, so that failed writes never leave pointers to bytes
+    /// which were not written.
+    fn write_labels<L: AsRef<[u8]>>(&mut self, labels: &[L]) -> Result<()> {
+        let mut suffixes = Vec::new();
+
+        for (index, label) in labels.iter().enumerate() {
+            if let Some(names) = &self.names {
+                let suffix = encode_labels(&labels[index..]);
+
+                if let Some(offset) = names.suffixes.get(&suffix).copied() {
+                    self.try_write((0xC000 | offset).to_be_bytes())?;
+                    self.insert_suffixes(suffixes);
+                    return Ok(());
+                }
+
+                let offset = self.buf.len() - names.base;
+                if offset <= MAX_POINTER_OFFSET {
+                    suffixes.push((suffix, offset as u16));
+                }
+            }
+
+            let label = label.as_ref();
+            self.try_push(label.len() as u8)?;
+            self.try_write(label)?;
+        }
+
+        self.try_push(0)?;
+        self.insert_suffixes(suffixes);
+        Ok(())
+    }
+
+    fn insert_suffixes(&mut self, suffixes: Vec<(Vec<u8>, u16)>) {
+        if let Some(names) = &mut self.names {
+            names.suffixes.extend(suffixes);
+        }
+    }
+
+    /// Reserves a length field of type `L` encoded with the endianness `E`
+    /// and enters a new span. The field is initially written as zero. Once
+    /// the body following the field is written, the field has to be filled
+    /// in with the length of the body by calling [`Writer::patch_length()`].
+    ///
+    /// Length slots can be nested. Spans entered after reserving the length
+    /// field have to be exited before the field is patched. Writers backed
+    /// by a sink don't support length fields and return the error
+    /// [`WriteError::PatchUnsupported`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{BigEndian, Write as _, Writer};
+    ///
+    /// let mut b = Writer::new();
+    ///
+    /// let slot = b.reserve_length::<u16, BigEndian>().unwrap();
+    /// 17752u16.write::<BigEndian>(&mut b).unwrap();
+    /// 69u8.write::<BigEndian>(&mut b).unwrap();
+    ///
+    /// assert_eq!(b.patch_length(slot), Ok(3));
+    /// assert_eq!(b.bytes(), &[0, 3, 69, 88, 69]);
+    /// ```
+    pub fn reserve_length<L, E>(&mut self) -> Result<LengthSlot<L, E>>
+    where
+        L: Write + Default + TryFrom<usize>,
+        E: Endianness,
+    {
+        ensure!(
+            !matches!(self.buf, Storage::Sink { .. }),
+            PatchUnsupportedSnafu
+        );
+
+        let index = self.len();
+        L::default().write::<E>(self)?;
+        self.enter();
+
+        Ok(LengthSlot {
+            index,
+            depth: self.spans.len(),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Exits the span entered by [`Writer::reserve_length()`] and fills in
+    /// the reserved length field with the number of bytes written since.
+    /// Returns the length of the body.
+    ///
+    /// If the length doesn't fit into the field type `L`, the error
+    /// [`WriteError::LengthOverflow`] is returned. If spans entered after
+    /// reserving the field were not exited or the buffer was cleared in the
+    /// meantime, the error [`WriteError::InvalidLengthSlot`] is returned.
+    pub fn patch_length<L, E>(&mut self, slot: LengthSlot<L, E>) -> Result
+    where
+        L: Write + Default + TryFrom<usize>,
+        E: Endianness,
+    {
+        ensure!(
+            self.spans.len() == slot.depth && slot.index < self.len(),
+            InvalidLengthSlotSnafu
+        );
+
+        let len = self.exit();
+        let value = L::try_from(len).map_err(|_| LengthOverflowSnafu { len }.build())?;
+
+        let mut field = [0; MAX_LENGTH_FIELD_LEN];
+        let field = encode_length_field::<L, E>(value, &mut field)?;
+
+        self.buf.bytes_mut()[slot.index..slot.index + field.len()].copy_from_slice(field);
+        Ok(len)
     }
 
     pub fn enter(&mut self) {
@@ -223,20 +1179,257 @@ impl Writer {
         n
     }
 
-    /// Returns the content of [`WriteBuffer`] as a slice of bytes.
+    /// Returns the content of [`WriteBuffer`] as a slice of bytes. Writers
+    /// backed by a sink always return an empty slice.
     pub fn bytes(&self) -> &[u8] {
-        self.buf.as_slice()
+        self.buf.bytes()
     }
 
     /// Returns the content [`WriteBuffer`] as an owned vector of bytes.
     pub fn owned_bytes(&self) -> Vec<u8> {
-        self.buf.clone()
+        self.buf.bytes().to_vec()
+    }
+
+    /// Returns a [`BitWriter`] which writes individual bits to this writer
+    /// using the bit order `order`. See [`BitWriter`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{BitOrder, Writer};
+    ///
+    /// let mut b = Writer::new();
+    /// let mut bits = b.bits(BitOrder::MsbFirst);
+    ///
+    /// bits.write_bits(4, 4).unwrap();
+    /// bits.write_bits(5, 4).unwrap();
+    /// assert_eq!(bits.finish(), Ok(1));
+    ///
+    /// assert_eq!(b.bytes(), &[0x45]);
+    /// ```
+    pub fn bits(&mut self, order: BitOrder) -> BitWriter<'_, 'a> {
+        BitWriter::new(self, order)
+    }
+}
+
+/// A bit packer layered on top of a [`Writer`]. It writes arbitrary N-bit
+/// unsigned values and single flags, which is useful to build bit fields of
+/// protocol headers, like the IPv4 version and IHL or the DNS flags.
+///
+/// Bytes are pushed to the underlying [`Writer`] as soon as all 8 bits of
+/// them are written, which keeps the span accounting of [`Writer::enter()`]
+/// and [`Writer::exit()`] correct. A partial byte is only pushed when the
+/// [`BitWriter`] is aligned with [`BitWriter::align()`] or finished with
+/// [`BitWriter::finish_padded()`]. Dropping a [`BitWriter`] discards any
+/// bits of a partial byte. If pushing a byte fails, because the underlying
+/// [`Writer`] is full, the error is returned by the write which completed
+/// the byte.
+///
+/// ### Example
+///
+/// ```
+/// use binbuf::{BitOrder, Writer};
+///
+/// // DNS flags: QR=1, OPCODE=0, AA=0, TC=0, RD=1, RA=1, Z=0, RCODE=3
+/// let mut b = Writer::new();
+/// let mut bits = b.bits(BitOrder::MsbFirst);
+///
+/// bits.write_flag(true).unwrap();
+/// bits.write_bits(0, 4).unwrap();
+/// bits.write_bits(0, 2).unwrap();
+/// bits.write_flag(true).unwrap();
+/// bits.write_flag(true).unwrap();
+/// bits.write_bits(0, 3).unwrap();
+/// bits.write_bits(3, 4).unwrap();
+///
+/// assert_eq!(bits.finish(), Ok(2));
+/// assert_eq!(b.bytes(), &[0x81, 0x83]);
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<'w, 'a> {
+    writer: &'w mut Writer<'a>,
+    order: BitOrder,
+
+    /// The partial byte bits are currently written to.
+    byte: u8,
+
+    /// The number of bits already written to `byte`.
+    filled: u32,
+
+    /// The number of complete bytes pushed to the writer.
+    written: usize,
+}
+
+impl<'w, 'a> BitWriter<'w, 'a> {
+    /// Create a new [`BitWriter`] writing to `writer` using the bit order
+    /// `order`.
+    pub fn new(writer: &'w mut Writer<'a>, order: BitOrder) -> Self {
+        Self {
+            writer,
+            order,
+            byte: 0,
+            filled: 0,
+            written: 0,
+        }
+    }
+
+    /// Write the lowest `nbits` bits of `value`. At most 64 bits can be
+    /// written at once. If `nbits` exceeds this limit, the error
+    /// [`WriteError::BitCountOverflow`] is returned. If `value` doesn't fit
+    /// into `nbits` bits, the error [`WriteError::BitValueOverflow`] is
+    /// returned. In both cases no bits are written.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{BitOrder, Writer};
+    ///
+    /// let mut b = Writer::new();
+    ///
+    /// let mut bits = b.bits(BitOrder::MsbFirst);
+    /// bits.write_bits(0b101, 3).unwrap();
+    /// bits.write_bits(0b10001, 5).unwrap();
+    ///
+    /// let mut bits = b.bits(BitOrder::LsbFirst);
+    /// bits.write_bits(0b001, 3).unwrap();
+    /// bits.write_bits(0b10110, 5).unwrap();
+    ///
+    /// assert_eq!(b.bytes(), &[0b1011_0001, 0b1011_0001]);
+    /// ```
+    pub fn write_bits(&mut self, value: u64, nbits: u32) -> Result<()> {
+        ensure!(nbits <= u64::BITS, BitCountOverflowSnafu { nbits });
+        ensure!(
+            nbits == u64::BITS || value >> nbits == 0,
+            BitValueOverflowSnafu { value, nbits }
+        );
+
+        let mut left = nbits;
+
+        while left > 0 {
+            // A complete byte is left over if pushing it failed previously
+            if self.filled == 8 {
+                self.push_byte()?;
+            }
+
+            let free = 8 - self.filled;
+            let n = left.min(free);
+            let mask = ((1u16 << n) - 1) as u8;
+
+            match self.order {
+                BitOrder::MsbFirst => {
+                    let bits = (value >> (left - n)) as u8 & mask;
+                    self.byte |= bits << (free - n);
+                }
+                BitOrder::LsbFirst => {
+                    let bits = (value >> (nbits - left)) as u8 & mask;
+                    self.byte |= bits << self.filled;
+                }
+            }
+
+            self.filled += n;
+            left -= n;
+
+            if self.filled == 8 {
+                self.push_byte()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write a single bit representing `flag`.
+    pub fn write_flag(&mut self, flag: bool) -> Result<()> {
+        self.write_bits(flag as u64, 1)
+    }
+
+    /// Pad the current partial byte with zero bits and push it to the
+    /// underlying [`Writer`], so that the next write starts at the next byte
+    /// boundary. Returns the number of padding bits.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use binbuf::{BitOrder, Writer};
+    ///
+    /// let mut b = Writer::new();
+    /// let mut bits = b.bits(BitOrder::MsbFirst);
+    ///
+    /// bits.write_bits(3, 2).unwrap();
+    /// assert_eq!(bits.align(), Ok(6));
+    /// bits.write_bits(0x45, 8).unwrap();
+    ///
+    /// assert_eq!(b.bytes(), &[0xC0, 0x45]);
+    /// ```
+    pub fn align(&mut self) -> Result<u32> {
+        if self.filled == 0 {
+            return Ok(0);
+        }
+
+        let padding = 8 - self.filled;
+        self.push_byte()?;
+        Ok(padding)
+    }
+
+    /// Returns if the cursor is positioned at a byte boundary.
+    pub fn is_aligned(&self) -> bool {
+        self.filled == 0
+    }
+
+    /// Finish writing bits and return the number of bytes written by this
+    /// [`BitWriter`]. If the last byte is incomplete, the error
+    /// [`WriteError::UnalignedBits`] is returned and the partial byte is
+    /// discarded.
+    pub fn finish(self) -> Result {
+        ensure!(
+            self.filled == 0,
+            UnalignedBitsSnafu {
+                nbits: 8 - self.filled
+            }
+        );
+
+        Ok(self.written)
+    }
+
+    /// Finish writing bits and return the number of bytes written by this
+    /// [`BitWriter`]. If the last byte is incomplete, it is padded with zero
+    /// bits.
+    pub fn finish_padded(mut self) -> Result {
+        self.align()?;
+        Ok(self.written)
+    }
+
+    /// Returns the bit order of this [`BitWriter`].
+    pub fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    fn push_byte(&mut self) -> Result<()> {
+        self.writer.try_push(self.byte)?;
+        self.written += 1;
+
+        self.byte = 0;
+        self.filled = 0;
+
+        Ok(())
     }
 }
 
 pub trait Write: Sized {
+    /// The byte orders supported by this type. Writing with an unsupported
+    /// [`Endianness`] returns the error [`WriteError::BigEndianNotSupported`]
+    /// or [`WriteError::LittleEndianNotSupported`].
+    const ENDIANNESS: SupportedEndianness = SupportedEndianness::Both;
+
     fn write<E: Endianness>(&self, buf: &mut Writer) -> Result<usize> {
-        E::write(self, buf)
+        Self::ENDIANNESS.verify_write::<E>()?;
+        let n = E::write(self, buf)?;
+
+        // Implementations can use the infallible Writer::write, which only
+        // records errors of fixed size buffers and sinks
+        match buf.take_error() {
+            Some(err) => Err(err),
+            None => Ok(n),
+        }
     }
 
     #[allow(unused_variables)]
@@ -250,12 +1443,67 @@ pub trait Write: Sized {
     }
 }
 
+/// Types whose encoding is selected by a tag which is written separately,
+/// the counterpart of [`ReadPayload`](crate::read::ReadPayload). Deriving
+/// `Write` on enums with variants carrying data implements this trait.
+pub trait WritePayload {
+    /// The type of the tag selecting the encoding.
+    type Tag;
+
+    /// Returns the tag which selects the encoding of [`Self`].
+    fn tag(&self) -> Self::Tag;
+
+    /// Write [`Self`] without the tag to a [`Writer`] with the endianness
+    /// `E`.
+    fn write_payload<E: Endianness>(&self, buf: &mut Writer) -> Result;
+}
+
 into_buffer_and_writeable_impl!(u8, 1);
 into_buffer_and_writeable_impl!(u16, 2);
 into_buffer_and_writeable_impl!(u32, 4);
 into_buffer_and_writeable_impl!(u64, 8);
 into_buffer_and_writeable_impl!(u128, 16);
 
+into_buffer_and_writeable_impl!(i8, 1);
+into_buffer_and_writeable_impl!(i16, 2);
+into_buffer_and_writeable_impl!(i32, 4);
+into_buffer_and_writeable_impl!(i64, 8);
+into_buffer_and_writeable_impl!(i128, 16);
+
+into_buffer_and_writeable_float_impl!(f32);
+into_buffer_and_writeable_float_impl!(f64);
+
+/// A length field reserved by [`Writer::reserve_length()`]. It must be passed
+/// to [`Writer::patch_length()`] to fill in the actual length.
+#[derive(Debug)]
+#[must_use = "the reserved length field must be filled in using Writer::patch_length"]
+pub struct LengthSlot<L, E> {
+    index: usize,
+    depth: usize,
+    _marker: PhantomData<(L, E)>,
+}
+
+/// Encodes the length field `value` into `buf` without allocating and
+/// returns the encoded bytes.
+fn encode_length_field<L: Write, E: Endianness>(value: L, buf: &mut [u8]) -> Result<&[u8]> {
+    let n = value.write::<E>(&mut Writer::new_fixed(&mut *buf))?;
+    Ok(&buf[..n])
+}
+
+/// Encodes `labels` as a sequence of length-prefixed labels, without the
+/// terminating null label.
+fn encode_labels<L: AsRef<[u8]>>(labels: &[L]) -> Vec<u8> {
+    let mut buf = Vec::new();
+
+    for label in labels {
+        let label = label.as_ref();
+        buf.push(label.len() as u8);
+        buf.extend_from_slice(label);
+    }
+
+    buf
+}
+
 impl<T: Write> Write for Vec<T> {
     fn write<E: Endianness>(&self, buf: &mut Writer) -> Result {
         buf.enter();
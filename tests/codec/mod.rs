@@ -0,0 +1,108 @@
+use std::net::Ipv4Addr;
+
+use binbuf::{
+    codec::{Codec, CodecError},
+    read::Error,
+    write::WriteError,
+    BigEndian, LittleEndian,
+};
+use tokio_util::{
+    bytes::BytesMut,
+    codec::{Decoder, Encoder},
+};
+
+#[test]
+fn test_codec_roundtrip() {
+    let mut codec = Codec::<Ipv4Addr, u32, LittleEndian>::new();
+    let mut buf = BytesMut::new();
+
+    codec.encode(Ipv4Addr::LOCALHOST, &mut buf).unwrap();
+    codec.encode(Ipv4Addr::BROADCAST, &mut buf).unwrap();
+    assert_eq!(&buf[..8], &[4, 0, 0, 0, 1, 0, 0, 127]);
+
+    assert_eq!(codec.decode(&mut buf), Ok(Some(Ipv4Addr::LOCALHOST)));
+    assert_eq!(codec.decode(&mut buf), Ok(Some(Ipv4Addr::BROADCAST)));
+    assert_eq!(codec.decode(&mut buf), Ok(None));
+}
+
+#[test]
+fn test_codec_prefix_endianness() {
+    let mut codec = Codec::<u32, u16, LittleEndian, BigEndian>::new();
+    let mut buf = BytesMut::new();
+
+    codec.encode(1163411789, &mut buf).unwrap();
+    assert_eq!(&buf[..], &[0, 4, 77, 65, 88, 69]);
+
+    assert_eq!(codec.decode(&mut buf), Ok(Some(1163411789)));
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn test_codec_decode_incomplete() {
+    let mut codec = Codec::<u32, u16, BigEndian>::new();
+    let mut buf = BytesMut::new();
+
+    // Incomplete length prefix
+    buf.extend_from_slice(&[0]);
+    assert_eq!(codec.decode(&mut buf), Ok(None));
+
+    // Incomplete body
+    buf.extend_from_slice(&[4, 69, 88]);
+    assert_eq!(codec.decode(&mut buf), Ok(None));
+    assert_eq!(buf.len(), 4);
+
+    buf.extend_from_slice(&[65, 77, 0]);
+    assert_eq!(codec.decode(&mut buf), Ok(Some(1163411789)));
+    assert_eq!(&buf[..], &[0]);
+}
+
+#[test]
+fn test_codec_decode_malformed() {
+    let mut codec = Codec::<u32, u8, BigEndian>::new();
+
+    let mut buf = BytesMut::from(&[2, 69, 88][..]);
+    assert_eq!(
+        codec.decode(&mut buf),
+        Err(CodecError::Decode {
+            source: Error::BufferTooShort
+        })
+    );
+
+    let mut buf = BytesMut::from(&[5, 69, 88, 65, 77, 80][..]);
+    assert_eq!(
+        codec.decode(&mut buf),
+        Err(CodecError::TrailingBytes { len: 1 })
+    );
+}
+
+#[test]
+fn test_codec_max_frame_len() {
+    let mut codec = Codec::<u32, u16, BigEndian>::new().with_max_frame_len(3);
+
+    let mut buf = BytesMut::from(&[0, 4][..]);
+    assert_eq!(
+        codec.decode(&mut buf),
+        Err(CodecError::FrameTooLong { len: 4, max: 3 })
+    );
+
+    let mut buf = BytesMut::new();
+    assert_eq!(
+        codec.encode(1163411789, &mut buf),
+        Err(CodecError::FrameTooLong { len: 4, max: 3 })
+    );
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn test_codec_encode_length_overflow() {
+    let mut codec = Codec::<String, u8, BigEndian>::new();
+    let mut buf = BytesMut::new();
+
+    assert_eq!(
+        codec.encode("a".repeat(256), &mut buf),
+        Err(CodecError::Encode {
+            source: WriteError::LengthOverflow { len: 256 }
+        })
+    );
+    assert!(buf.is_empty());
+}
@@ -1,2 +1,8 @@
 mod read;
 mod write;
+
+#[cfg(feature = "codec")]
+mod codec;
+
+#[cfg(feature = "tokio")]
+mod tokio;
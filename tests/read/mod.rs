@@ -2,11 +2,21 @@ use std::net::{Ipv4Addr, Ipv6Addr};
 
 use binbuf::{read::Reader, BigEndian, Read};
 
+mod read_bits;
 mod read_buffer;
+mod read_cstring;
 mod read_derive_enum;
 mod read_derive_struct;
+mod read_domain_name;
+mod read_float;
 mod read_impl;
+mod read_length_prefixed;
 mod read_multi;
+mod read_ref;
+mod read_signed;
+mod read_stream;
+mod read_string;
+mod read_varint;
 
 #[test]
 fn test_read_u8() {
@@ -0,0 +1,104 @@
+use binbuf::{read::Error, BitOrder, Read, Reader};
+
+#[test]
+fn test_read_bits_ipv4_header() {
+    let b = &[0x45, 0x00, 0x00, 0x54];
+    let mut b = Reader::new(b);
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    assert_eq!(bits.read_bits(4), Ok(4));
+    assert_eq!(bits.read_bits(4), Ok(5));
+    assert_eq!(bits.read_bits(6), Ok(0));
+    assert_eq!(bits.read_bits(2), Ok(0));
+
+    assert_eq!(u16::read_be(&mut b), Ok(84));
+}
+
+#[test]
+fn test_read_bits_across_bytes() {
+    let b = &[0b1010_1010, 0b1100_1100, 0b1111_0000];
+    let mut b = Reader::new(b);
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    assert_eq!(bits.read_bits(3), Ok(0b101));
+    assert_eq!(bits.read_bits(10), Ok(0b01_0101_1001));
+    assert_eq!(bits.read_bits(11), Ok(0b100_1111_0000));
+    assert!(bits.is_aligned());
+}
+
+#[test]
+fn test_read_bits_lsb_first() {
+    let b = &[0b1010_1010, 0b1100_1100];
+    let mut b = Reader::new(b);
+
+    let mut bits = b.bits(BitOrder::LsbFirst);
+    assert_eq!(bits.read_flag(), Ok(false));
+    assert_eq!(bits.read_bits(3), Ok(0b101));
+    assert_eq!(bits.read_bits(8), Ok(0b1100_1010));
+    assert_eq!(bits.read_bits(4), Ok(0b1100));
+}
+
+#[test]
+fn test_read_bits_64() {
+    let b = &[0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+
+    let mut r = Reader::new(b);
+    let mut bits = r.bits(BitOrder::MsbFirst);
+    assert_eq!(bits.read_bits(64), Ok(0x0123456789ABCDEF));
+
+    let mut r = Reader::new(b);
+    let mut bits = r.bits(BitOrder::LsbFirst);
+    assert_eq!(bits.read_bits(64), Ok(0xEFCDAB8967452301));
+}
+
+#[test]
+fn test_read_bits_align() {
+    let b = &[0xFF, 0x45];
+    let mut b = Reader::new(b);
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    assert_eq!(bits.read_bits(3), Ok(7));
+    assert_eq!(bits.bit_offset(), 3);
+    assert!(!bits.is_aligned());
+
+    assert_eq!(bits.align(), 5);
+    assert_eq!(bits.bit_offset(), 8);
+    assert_eq!(bits.align(), 0);
+
+    assert_eq!(b.pop(), Ok(0x45));
+}
+
+#[test]
+fn test_read_bits_too_short() {
+    let b = &[0xFF, 0x45];
+    let mut b = Reader::new(b);
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    assert_eq!(bits.read_bits(5), Ok(31));
+    assert_eq!(
+        bits.read_bits(12),
+        Err(Error::BitBufferTooShort {
+            nbits: 12,
+            offset: 5
+        })
+    );
+
+    // The failed read must not consume any bits
+    assert_eq!(bits.read_bits(11), Ok(0b111_0100_0101));
+}
+
+#[test]
+fn test_read_bits_count_overflow() {
+    let b = &[0; 16];
+    let mut b = Reader::new(b);
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    assert_eq!(bits.read_flag(), Ok(false));
+    assert_eq!(
+        bits.read_bits(65),
+        Err(Error::BitCountOverflow {
+            nbits: 65,
+            offset: 1
+        })
+    );
+}
@@ -0,0 +1,85 @@
+use binbuf::{
+    read::{Error, Terminator},
+    Reader,
+};
+
+#[test]
+fn test_read_cstring() {
+    let b = b"pxelinux.0\0\0rest";
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_cstring(None, Terminator::Required),
+        Ok(b"pxelinux.0".as_slice())
+    );
+    assert_eq!(
+        b.read_cstring(None, Terminator::Required),
+        Ok([].as_slice())
+    );
+    assert_eq!(b.offset(), 12);
+}
+
+#[test]
+fn test_read_cstring_max_len() {
+    let b = b"boot\0";
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_cstring(Some(3), Terminator::Required),
+        Err(Error::MaxLengthOverflow)
+    );
+    assert_eq!(b.offset(), 0);
+
+    assert_eq!(
+        b.read_cstring(Some(4), Terminator::Required),
+        Ok(b"boot".as_slice())
+    );
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_read_cstring_missing_terminator() {
+    let b = b"boot";
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_cstring(None, Terminator::Required),
+        Err(Error::BufferTooShort)
+    );
+    assert_eq!(b.offset(), 0);
+
+    assert_eq!(
+        b.read_cstring(Some(2), Terminator::Optional),
+        Ok(b"bo".as_slice())
+    );
+    assert_eq!(
+        b.read_cstring(None, Terminator::Optional),
+        Ok(b"ot".as_slice())
+    );
+}
+
+#[test]
+fn test_read_cstring_stream() {
+    let d: &[u8] = b"boot\0file\0";
+    let mut b = Reader::from_reader_with_capacity(1, d);
+
+    assert_eq!(
+        b.read_cstring(None, Terminator::Required),
+        Ok(b"boot".as_slice())
+    );
+    assert_eq!(
+        b.read_cstring(Some(4), Terminator::Required),
+        Ok(b"file".as_slice())
+    );
+}
+
+#[test]
+fn test_read_padded() {
+    let b = b"tftp\0\0\0\0root    \0\0";
+    let mut b = Reader::new(b);
+
+    assert_eq!(b.read_padded(8, 0), Ok(b"tftp".as_slice()));
+    assert_eq!(b.read_padded(8, b' '), Ok(b"root".as_slice()));
+    assert_eq!(b.read_padded(2, 0), Ok([].as_slice()));
+    assert_eq!(b.read_padded(1, 0), Err(Error::BufferTooShort));
+}
@@ -42,3 +42,238 @@ fn test_readable_derive_invalid() {
         Err(err) => assert_eq!(err, Error::InvalidData),
     }
 }
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_signed_repr() {
+    use binbuf::{read::Error, BigEndian, Read, Reader};
+
+    #[derive(Debug, PartialEq, Read)]
+    #[binbuf(repr = "i16")]
+    enum Code {
+        Request,
+        Reply,
+    }
+
+    let b = vec![0, 1, 0, 0, 0xFF, 0xFF];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(Code::read::<BigEndian>(&mut buf), Ok(Code::Reply));
+    assert_eq!(Code::read::<BigEndian>(&mut buf), Ok(Code::Request));
+    assert_eq!(Code::read::<BigEndian>(&mut buf), Err(Error::InvalidData));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_signed_repr_max() {
+    use binbuf::{read::Error, BigEndian, Read, Reader};
+
+    // Positional discriminants must not wrap around to negative values
+    #[derive(Debug, PartialEq, Read)]
+    #[binbuf(repr = "i8")]
+    enum Code {
+        Second = 126,
+        Last,
+    }
+
+    let b = vec![0x7E, 0x7F, 0x80];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(Code::read::<BigEndian>(&mut buf), Ok(Code::Second));
+    assert_eq!(Code::read::<BigEndian>(&mut buf), Ok(Code::Last));
+    assert_eq!(Code::read::<BigEndian>(&mut buf), Err(Error::InvalidData));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_discriminants() {
+    use binbuf::{read::Error, BigEndian, Read, Reader};
+
+    #[derive(Debug, PartialEq, Read)]
+    #[binbuf(repr = "i8")]
+    enum Code {
+        Negative = -2,
+        Next,
+        Sparse = 0x0F,
+        Last,
+    }
+
+    let b = vec![0xFE, 0xFF, 0x0F, 0x10, 0x00];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(Code::read::<BigEndian>(&mut buf), Ok(Code::Negative));
+    assert_eq!(Code::read::<BigEndian>(&mut buf), Ok(Code::Next));
+    assert_eq!(Code::read::<BigEndian>(&mut buf), Ok(Code::Sparse));
+    assert_eq!(Code::read::<BigEndian>(&mut buf), Ok(Code::Last));
+    assert_eq!(Code::read::<BigEndian>(&mut buf), Err(Error::InvalidData));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_other_variant() {
+    use binbuf::{BigEndian, Read, Reader};
+
+    #[derive(Debug, PartialEq, Read)]
+    #[binbuf(repr = "u16")]
+    #[repr(u16)]
+    enum RecordType {
+        A = 1,
+        Ns = 2,
+        Aaaa = 28,
+        #[binbuf(other)]
+        Unknown(u16),
+    }
+
+    let b = vec![0, 28, 0, 2, 0xFF, 0x00];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(
+        RecordType::read::<BigEndian>(&mut buf),
+        Ok(RecordType::Aaaa)
+    );
+    assert_eq!(RecordType::read::<BigEndian>(&mut buf), Ok(RecordType::Ns));
+    assert_eq!(
+        RecordType::read::<BigEndian>(&mut buf),
+        Ok(RecordType::Unknown(0xFF00))
+    );
+    assert_eq!(RecordType::try_from(1), Ok(RecordType::A));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_data_variants() {
+    use binbuf::{read::Error, BigEndian, Read, Reader};
+
+    #[derive(Debug, PartialEq, Read)]
+    #[repr(u8)]
+    enum Icmp {
+        EchoReply { id: u16, seq: u16 } = 0,
+        Unreachable(u8, u32) = 3,
+        SourceQuench = 4,
+    }
+
+    let b = vec![0, 0, 7, 0, 1, 3, 1, 0, 0, 0, 0, 4, 5];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(
+        Icmp::read::<BigEndian>(&mut buf),
+        Ok(Icmp::EchoReply { id: 7, seq: 1 })
+    );
+    assert_eq!(
+        Icmp::read::<BigEndian>(&mut buf),
+        Ok(Icmp::Unreachable(1, 0))
+    );
+    assert_eq!(Icmp::read::<BigEndian>(&mut buf), Ok(Icmp::SourceQuench));
+    assert_eq!(Icmp::read::<BigEndian>(&mut buf), Err(Error::InvalidData));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_external_tag() {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use binbuf::{BigEndian, Read, ReadPayload, Reader};
+
+    #[derive(Debug, PartialEq, Read)]
+    #[binbuf(repr = "u16", tag = "external")]
+    #[repr(u16)]
+    enum RData {
+        A(Ipv4Addr) = 1,
+        Aaaa(Ipv6Addr) = 28,
+        #[binbuf(other)]
+        Unknown(u16),
+    }
+
+    let b = vec![127, 0, 0, 1];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(
+        RData::read_payload::<BigEndian>(1, &mut buf),
+        Ok(RData::A(Ipv4Addr::LOCALHOST))
+    );
+    assert_eq!(
+        RData::read_payload::<BigEndian>(99, &mut buf),
+        Ok(RData::Unknown(99))
+    );
+    assert!(RData::read_payload::<BigEndian>(28, &mut buf).is_err());
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_enum_error() {
+    use binbuf::{
+        read::{self, TryRead},
+        BigEndian, Read, Reader,
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct MessageError(read::Error);
+
+    impl From<read::Error> for MessageError {
+        fn from(err: read::Error) -> Self {
+            Self(err)
+        }
+    }
+
+    #[derive(Debug, PartialEq, Read)]
+    #[binbuf(error = "MessageError")]
+    enum Kind {
+        Ping,
+        Pong,
+    }
+
+    #[derive(Debug, PartialEq, Read)]
+    #[binbuf(error = "MessageError")]
+    #[repr(u8)]
+    enum Message {
+        Ping(Kind) = 1,
+        Data { len: u16 } = 2,
+    }
+
+    let b = vec![1, 1, 2, 0, 8, 1, 7];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(
+        Message::try_read::<BigEndian>(&mut buf),
+        Ok(Message::Ping(Kind::Pong))
+    );
+    assert_eq!(
+        Message::try_read::<BigEndian>(&mut buf),
+        Ok(Message::Data { len: 8 })
+    );
+    assert_eq!(
+        Message::try_read::<BigEndian>(&mut buf),
+        Err(MessageError(read::Error::InvalidData))
+    );
+    assert_eq!(
+        Kind::try_from(3),
+        Err(MessageError(read::Error::InvalidData))
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_enum_generics() {
+    use std::fmt::Debug;
+
+    use binbuf::{BigEndian, Read, Reader};
+
+    #[derive(Debug, PartialEq, Read)]
+    #[repr(u8)]
+    enum Maybe<T>
+    where
+        T: Debug,
+    {
+        None = 0,
+        Some(T) = 1,
+    }
+
+    let b = vec![1, 0, 53, 0];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(
+        Maybe::<u16>::read::<BigEndian>(&mut buf),
+        Ok(Maybe::Some(53))
+    );
+    assert_eq!(Maybe::<u16>::read::<BigEndian>(&mut buf), Ok(Maybe::None));
+}
@@ -105,20 +105,61 @@ fn test_readable_derive_nested() {
 #[cfg(feature = "derive")]
 #[test]
 fn test_readable_derive_struct_attrs_error() {
-    use binbuf::{read::Error, BigEndian, Read, Reader};
+    use binbuf::{
+        read::{self, TryRead},
+        BigEndian, Endianness, Read, Reader,
+    };
+
+    #[derive(Debug, PartialEq)]
+    enum ReadError {
+        ZeroPort,
+        Buffer(read::Error),
+    }
+
+    impl From<read::Error> for ReadError {
+        fn from(err: read::Error) -> Self {
+            Self::Buffer(err)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Port(u16);
+
+    impl TryRead for Port {
+        type Error = ReadError;
+
+        fn try_read<E: Endianness>(buf: &mut Reader) -> Result<Self, Self::Error> {
+            match u16::read::<E>(buf)? {
+                0 => Err(ReadError::ZeroPort),
+                port => Ok(Self(port)),
+            }
+        }
+    }
 
     #[derive(Read, Debug, PartialEq)]
     #[binbuf(error = "ReadError")]
     struct Data {
-        v1: u64,
-        v2: u16,
+        v1: u32,
+        port: Port,
     }
 
-    let b = vec![69, 88, 65, 77, 80, 76, 69, 33];
+    let b = vec![69, 88, 65, 77, 0, 53, 69, 33, 0, 0, 0, 0, 1];
     let mut buf = Reader::new(b.as_slice());
 
-    let err = Data::read::<BigEndian>(&mut buf).unwrap_err();
-    assert_eq!(err, Error::BufferTooShort);
+    assert_eq!(
+        Data::try_read::<BigEndian>(&mut buf),
+        Ok(Data {
+            v1: 0x4558414D,
+            port: Port(53)
+        })
+    );
+    assert_eq!(
+        Data::try_read::<BigEndian>(&mut buf),
+        Err(ReadError::ZeroPort)
+    );
+
+    let err = Data::try_read::<BigEndian>(&mut buf).unwrap_err();
+    assert_eq!(err, ReadError::Buffer(read::Error::BufferTooShort));
 }
 
 #[cfg(feature = "derive")]
@@ -133,3 +174,275 @@ fn test_readable_derive_struct_field_attrs() {
         v2: u16,
     }
 }
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_signed_fields() {
+    use binbuf::{BigEndian, Read, Reader};
+
+    #[derive(Read)]
+    struct Data {
+        v1: i8,
+        v2: i16,
+        v3: i32,
+    }
+
+    let b = vec![0xFF, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF, 0x85];
+    let mut buf = Reader::new(b.as_slice());
+
+    let data = Data::read::<BigEndian>(&mut buf).unwrap();
+    assert_eq!(data.v1, -1);
+    assert_eq!(data.v2, -2);
+    assert_eq!(data.v3, -123);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_varint_fields() {
+    use binbuf::{
+        varint::{QuicVarInt, ZigZag},
+        BigEndian, Read, Reader,
+    };
+
+    #[derive(Read)]
+    struct Data {
+        stream_id: QuicVarInt,
+        delta: ZigZag,
+        flags: u8,
+    }
+
+    let b = vec![0x7B, 0xBD, 0x03, 0x45];
+    let mut buf = Reader::new(b.as_slice());
+
+    let data = Data::read::<BigEndian>(&mut buf).unwrap();
+    assert_eq!(data.stream_id, QuicVarInt(15293));
+    assert_eq!(data.delta, ZigZag(-2));
+    assert_eq!(data.flags, 69);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_string_fields() {
+    use binbuf::{BigEndian, Read, Reader};
+
+    #[derive(Read)]
+    struct Data {
+        #[binbuf(padded = 8)]
+        sname: Vec<u8>,
+        #[binbuf(cstring, max_len = 16)]
+        file: Vec<u8>,
+        #[binbuf(cstring, terminator = "optional")]
+        comment: Vec<u8>,
+    }
+
+    let b = b"tftp\0\0\0\0boot\0note";
+    let mut buf = Reader::new(b.as_slice());
+
+    let data = Data::read::<BigEndian>(&mut buf).unwrap();
+    assert_eq!(data.sname, b"tftp");
+    assert_eq!(data.file, b"boot");
+    assert_eq!(data.comment, b"note");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_borrowed_fields() {
+    use binbuf::{read::ReadRef, string::StringPrefix, BigEndian, Read, Reader};
+
+    #[derive(Read)]
+    struct Header<'a> {
+        kind: u8,
+        name: &'a str,
+    }
+
+    #[derive(Read)]
+    struct Packet<'a> {
+        header: Header<'a>,
+        id: u16,
+        payload: &'a [u8],
+    }
+
+    let b = vec![1, 4, 112, 105, 110, 103, 0, 69, 3, 88, 65, 77];
+
+    let packet = {
+        let mut buf = Reader::new(b.as_slice());
+        buf.set_string_prefix(StringPrefix::U8);
+
+        Packet::read_ref::<BigEndian>(&mut buf).unwrap()
+    };
+
+    assert_eq!(packet.header.kind, 1);
+    assert_eq!(packet.header.name, "ping");
+    assert_eq!(packet.id, 69);
+    assert_eq!(packet.payload, &[88, 65, 77]);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_ref_encoded_fields() {
+    use binbuf::{read::ReadRef, BigEndian, Read, Reader};
+
+    #[derive(Read)]
+    struct Borrowed<'a> {
+        #[binbuf(cstring)]
+        name: &'a [u8],
+        port: u16,
+        #[binbuf(padded = 6, pad = 32)]
+        user: &'a [u8],
+    }
+
+    let b = b"boot\0\0\x45root  ".to_vec();
+
+    let borrowed = {
+        let mut buf = Reader::new(b.as_slice());
+        Borrowed::read_ref::<BigEndian>(&mut buf).unwrap()
+    };
+
+    assert_eq!(borrowed.name, b"boot");
+    assert_eq!(borrowed.port, 69);
+    assert_eq!(borrowed.user, b"root");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_tuple_structs() {
+    use binbuf::{BigEndian, Read, Reader};
+
+    #[derive(Read, Debug, PartialEq)]
+    struct Port(u16);
+
+    #[derive(Read, Debug, PartialEq)]
+    struct Marker;
+
+    #[derive(Read, Debug, PartialEq)]
+    struct Endpoint(
+        Port,
+        Marker,
+        #[binbuf(skip_read = true)] u8,
+        #[binbuf(padded = 4)] Vec<u8>,
+    );
+
+    let b = vec![0, 53, 100, 110, 115, 0];
+    let mut buf = Reader::new(b.as_slice());
+
+    let endpoint = Endpoint::read::<BigEndian>(&mut buf).unwrap();
+    assert_eq!(endpoint, Endpoint(Port(53), Marker, 0, b"dns".to_vec()));
+    assert!(buf.is_empty());
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_generics() {
+    use std::{fmt::Debug, marker::PhantomData};
+
+    use binbuf::{BigEndian, Read, Reader};
+
+    #[derive(Read, Debug, PartialEq)]
+    struct Packet<T>
+    where
+        T: Debug,
+    {
+        id: u8,
+        body: T,
+    }
+
+    struct Tcp;
+
+    #[derive(Read, Debug, PartialEq)]
+    #[binbuf(read_bound = "T: Read")]
+    struct Tagged<T, P> {
+        value: T,
+        #[binbuf(skip_read = true)]
+        protocol: PhantomData<P>,
+    }
+
+    let b = vec![1, 0, 80, 0, 53];
+    let mut buf = Reader::new(b.as_slice());
+
+    let packet = Packet::<u16>::read::<BigEndian>(&mut buf).unwrap();
+    assert_eq!(packet, Packet { id: 1, body: 80 });
+
+    let tagged = Tagged::<u16, Tcp>::read::<BigEndian>(&mut buf).unwrap();
+    assert_eq!(tagged.value, 53);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_endianness() {
+    use binbuf::{read::Error, BigEndian, LittleEndian, Read, Reader, SupportedEndianness};
+
+    #[derive(Read, Debug, PartialEq)]
+    #[binbuf(endianness = "big")]
+    struct Header {
+        id: u16,
+    }
+
+    #[derive(Read, Debug, PartialEq)]
+    #[binbuf(endianness = "little")]
+    enum Kind {
+        A,
+        B,
+    }
+
+    assert_eq!(Header::ENDIANNESS, SupportedEndianness::Big);
+
+    let b = vec![0, 1, 1];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(
+        Header::read::<LittleEndian>(&mut buf),
+        Err(Error::LittleEndianNotSupported)
+    );
+    assert_eq!(Header::read::<BigEndian>(&mut buf), Ok(Header { id: 1 }));
+    assert_eq!(
+        Kind::read::<BigEndian>(&mut buf),
+        Err(Error::BigEndianNotSupported)
+    );
+    assert_eq!(Kind::read::<LittleEndian>(&mut buf), Ok(Kind::B));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_readable_derive_byte_order_overrides() {
+    use binbuf::{BigEndian, LittleEndian, Read, Reader};
+
+    #[derive(Read, Debug, PartialEq)]
+    struct Record {
+        caller: u16,
+        #[binbuf(big)]
+        port: u16,
+        #[binbuf(little)]
+        len: u32,
+    }
+
+    #[derive(Read, Debug, PartialEq)]
+    #[binbuf(little)]
+    struct Descriptor {
+        vendor: u16,
+        #[binbuf(big)]
+        version: u16,
+    }
+
+    let b = vec![1, 0, 0, 53, 8, 0, 0, 0];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(
+        Record::read::<LittleEndian>(&mut buf),
+        Ok(Record {
+            caller: 1,
+            port: 53,
+            len: 8
+        })
+    );
+
+    let b = vec![0x6B, 0x1D, 0x02, 0x00];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(
+        Descriptor::read::<BigEndian>(&mut buf),
+        Ok(Descriptor {
+            vendor: 0x1D6B,
+            version: 0x0200
+        })
+    );
+}
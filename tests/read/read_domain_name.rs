@@ -0,0 +1,145 @@
+use binbuf::{read::Error, Reader};
+
+fn labels(labels: &[&str]) -> Vec<Vec<u8>> {
+    labels.iter().map(|l| l.as_bytes().to_vec()).collect()
+}
+
+#[test]
+fn test_read_domain_name() {
+    let b = &[7, 101, 120, 97, 109, 112, 108, 101, 3, 99, 111, 109, 0, 69];
+    let mut b = Reader::new(b);
+
+    match b.read_domain_name() {
+        Ok(name) => assert_eq!(name, labels(&["example", "com"])),
+        Err(err) => panic!("{}", err),
+    }
+
+    assert_eq!(b.offset(), 13);
+    assert!(!b.jumped());
+}
+
+#[test]
+fn test_read_domain_name_root() {
+    let b = &[0];
+    let mut b = Reader::new(b);
+
+    assert_eq!(b.read_domain_name(), Ok(vec![]));
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_read_domain_name_compressed() {
+    let b = &[
+        3, 99, 111, 109, 0, // com
+        7, 101, 120, 97, 109, 112, 108, 101, 0xC0, 0, // example.com
+        3, 119, 119, 119, 0xC0, 5, // www.example.com
+        0xC0, 15, // www.example.com
+        69,
+    ];
+    let mut b = Reader::new(b);
+
+    assert_eq!(b.read_domain_name(), Ok(labels(&["com"])));
+    assert_eq!(b.read_domain_name(), Ok(labels(&["example", "com"])));
+    assert_eq!(b.offset(), 15);
+
+    assert_eq!(b.read_domain_name(), Ok(labels(&["www", "example", "com"])));
+    assert_eq!(b.offset(), 21);
+
+    assert_eq!(b.read_domain_name(), Ok(labels(&["www", "example", "com"])));
+    assert_eq!(b.offset(), 23);
+    assert!(!b.jumped());
+
+    assert_eq!(b.pop(), Ok(69));
+}
+
+#[test]
+fn test_read_domain_name_keeps_jump_indices() {
+    let b = &[3, 99, 111, 109, 0, 0xC0, 0, 69];
+    let mut b = Reader::new(b);
+
+    b.skipn(5).unwrap();
+    b.jump_to(5).unwrap();
+
+    assert_eq!(b.read_domain_name(), Ok(labels(&["com"])));
+    assert_eq!(b.offset(), 7);
+    assert!(b.jumped());
+
+    b.jump_back();
+    assert_eq!(b.offset(), 5);
+    assert!(!b.jumped());
+}
+
+#[test]
+fn test_read_domain_name_pointer_loop() {
+    let b = &[3, 99, 111, 109, 0xC0, 6, 0xC0, 0];
+    let mut b = Reader::new(b);
+
+    b.skipn(6).unwrap();
+
+    assert_eq!(b.read_domain_name(), Err(Error::PointerLoop { index: 0 }));
+    assert_eq!(b.offset(), 6);
+    assert!(!b.jumped());
+}
+
+#[test]
+fn test_read_domain_name_self_pointer() {
+    let b = &[0xC0, 0];
+    let mut b = Reader::new(b);
+
+    assert_eq!(b.read_domain_name(), Err(Error::PointerLoop { index: 0 }));
+    assert_eq!(b.offset(), 0);
+}
+
+#[test]
+fn test_read_domain_name_forward_pointer() {
+    let b = &[0xC0, 4, 0, 0, 3, 99, 111, 109, 0];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_domain_name(),
+        Err(Error::InvalidJump {
+            index: 4,
+            offset: 2
+        })
+    );
+    assert_eq!(b.offset(), 0);
+}
+
+#[test]
+fn test_read_domain_name_too_long() {
+    let mut b = Vec::new();
+    for _ in 0..4 {
+        b.push(63);
+        b.extend_from_slice(&[97; 63]);
+    }
+    b.push(0);
+
+    let mut b = Reader::new(b.as_slice());
+
+    assert_eq!(b.read_domain_name(), Err(Error::NameTooLong));
+    assert_eq!(b.offset(), 0);
+}
+
+#[test]
+fn test_read_domain_name_invalid_label_type() {
+    let b = &[3, 99, 111, 109, 0x40, 0];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_domain_name(),
+        Err(Error::InvalidLabelType {
+            byte: 0x40,
+            offset: 4
+        })
+    );
+    assert_eq!(b.offset(), 0);
+}
+
+#[test]
+fn test_read_domain_name_too_short() {
+    let b = &[7, 101, 120, 97];
+    let mut b = Reader::new(b);
+
+    assert_eq!(b.read_domain_name(), Err(Error::BufferTooShort));
+    assert_eq!(b.offset(), 0);
+}
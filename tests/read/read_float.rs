@@ -0,0 +1,37 @@
+use binbuf::{read::Reader, BigEndian, LittleEndian, Read, ReadableMulti};
+
+#[test]
+fn test_read_f32() {
+    let b = &[0x40, 0x49, 0x0F, 0xDB, 0xDB, 0x0F, 0x49, 0x40];
+    let mut b = Reader::new(b);
+
+    assert_eq!(f32::read::<BigEndian>(&mut b), Ok(std::f32::consts::PI));
+    assert_eq!(f32::read::<LittleEndian>(&mut b), Ok(std::f32::consts::PI));
+}
+
+#[test]
+fn test_read_f64() {
+    let b = &[0x40, 0x09, 0x21, 0xFB, 0x54, 0x44, 0x2D, 0x18];
+    let mut b = Reader::new(b);
+
+    assert_eq!(f64::read::<BigEndian>(&mut b), Ok(std::f64::consts::PI));
+}
+
+#[test]
+fn test_read_f64_nan_payload() {
+    let b = &[0x7F, 0xF0, 0, 0, 0, 0, 0, 1];
+    let mut b = Reader::new(b);
+
+    // Payloads are preserved when reading
+    let v = f64::read::<BigEndian>(&mut b).unwrap();
+    assert!(v.is_nan());
+    assert_eq!(v.to_bits(), 0x7FF0000000000001);
+}
+
+#[test]
+fn test_read_multi_f32() {
+    let b = &[0, 0, 0x80, 0x3F, 0, 0, 0, 0xC0];
+    let mut b = Reader::new(b);
+
+    assert_eq!(f32::read_multi::<LittleEndian, 2>(&mut b), Ok([1.0, -2.0]));
+}
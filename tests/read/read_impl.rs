@@ -22,3 +22,50 @@ fn test_readable_impl() {
     let data = Data::read::<BigEndian>(&mut buf).unwrap();
     assert_eq!(data.inner, 17752)
 }
+
+#[test]
+fn test_endianness_impl() {
+    use binbuf::{write, ByteOrder, SupportedEndianness, Write, Writer};
+
+    // Network byte order, implemented outside of the crate
+    struct Network;
+
+    impl Endianness for Network {
+        fn read<T: Read>(buf: &mut Reader) -> Result<T> {
+            T::read_be(buf)
+        }
+
+        fn write<T: Write>(n: &T, buf: &mut Writer) -> write::Result {
+            n.write_be(buf)
+        }
+    }
+
+    assert_eq!(Network::order(), ByteOrder::Big);
+    assert_eq!(SupportedEndianness::Big.verify_read::<Network>(), Ok(()));
+
+    let b = vec![0, 53];
+    let mut buf = Reader::new(b.as_slice());
+
+    assert_eq!(u16::read::<Network>(&mut buf), Ok(53));
+}
+
+#[test]
+#[should_panic(expected = "cannot determine the byte order")]
+fn test_endianness_impl_unknown_order() {
+    use binbuf::{write, Write, Writer};
+
+    // Neither big nor little endian, the order has to be overridden
+    struct Broken;
+
+    impl Endianness for Broken {
+        fn read<T: Read>(_buf: &mut Reader) -> Result<T> {
+            Err(binbuf::read::Error::InvalidData)
+        }
+
+        fn write<T: Write>(_n: &T, _buf: &mut Writer) -> write::Result {
+            Ok(0)
+        }
+    }
+
+    Broken::order();
+}
@@ -0,0 +1,116 @@
+use binbuf::{read::Error, varint::QuicVarInt, BigEndian, LittleEndian, Reader};
+
+#[test]
+fn test_read_length_prefixed_u8() {
+    let b = &[4, 88, 65, 77, 80, 0, 69];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_length_prefixed::<u8, BigEndian>(None),
+        Ok([88, 65, 77, 80].as_slice())
+    );
+    assert_eq!(
+        b.read_length_prefixed::<u8, BigEndian>(None),
+        Ok([].as_slice())
+    );
+    assert_eq!(b.pop(), Ok(69));
+}
+
+#[test]
+fn test_read_length_prefixed_u16() {
+    // SSH strings use u32 prefixes, TLS vectors u8, u16 or u24 prefixes
+    let b = &[0, 2, 69, 88, 2, 0, 65, 77];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_length_prefixed::<u16, BigEndian>(None),
+        Ok([69, 88].as_slice())
+    );
+    assert_eq!(
+        b.read_length_prefixed::<u16, LittleEndian>(None),
+        Ok([65, 77].as_slice())
+    );
+}
+
+#[test]
+fn test_read_length_prefixed_u32() {
+    let b = &[0, 0, 0, 3, 115, 115, 104];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_length_prefixed::<u32, BigEndian>(Some(3)),
+        Ok(b"ssh".as_slice())
+    );
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_read_length_prefixed_varint() {
+    let b = &[0x02, 69, 88];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_length_prefixed::<QuicVarInt, BigEndian>(None),
+        Ok([69, 88].as_slice())
+    );
+}
+
+#[test]
+fn test_read_length_prefixed_max_len() {
+    let b = &[0, 4, 88, 65, 77, 80];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_length_prefixed::<u16, BigEndian>(Some(3)),
+        Err(Error::MaxLengthOverflow)
+    );
+    assert_eq!(b.offset(), 0);
+}
+
+#[test]
+fn test_read_length_prefixed_too_short() {
+    let b = &[0, 0, 0, 5, 88, 65, 77, 80];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_length_prefixed::<u32, BigEndian>(None),
+        Err(Error::BufferTooShort)
+    );
+    assert_eq!(b.offset(), 0);
+}
+
+#[test]
+fn test_read_length_prefixed_stream() {
+    let d: &[u8] = &[0, 4, 88, 65, 77, 80, 0, 1, 69];
+    let mut b = Reader::from_reader_with_capacity(1, d);
+
+    assert_eq!(
+        b.read_length_prefixed::<u16, BigEndian>(None),
+        Ok([88, 65, 77, 80].as_slice())
+    );
+    assert_eq!(
+        b.read_length_prefixed::<u16, BigEndian>(None),
+        Ok([69].as_slice())
+    );
+}
+
+#[test]
+fn test_read_length_prefixed_limit() {
+    let d: &[u8] = &[255, 255, 255, 255, 0, 0, 0, 2, 88, 65];
+    let mut b = Reader::from_reader(d);
+    assert_eq!(b.length_limit(), 8 * 1024 * 1024);
+
+    assert_eq!(
+        b.read_length_prefixed::<u32, BigEndian>(None),
+        Err(Error::MaxLengthOverflow)
+    );
+    assert_eq!(b.offset(), 0);
+
+    b.set_length_limit(2);
+    b.skipn(4).unwrap();
+
+    assert_eq!(
+        b.read_length_prefixed::<u32, BigEndian>(None),
+        Ok([88, 65].as_slice())
+    );
+}
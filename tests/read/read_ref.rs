@@ -0,0 +1,105 @@
+use std::borrow::Cow;
+
+use binbuf::{
+    read::{Error, ReadRef, Terminator},
+    string::{StringPolicy, StringPrefix},
+    BigEndian, Reader,
+};
+
+#[test]
+fn test_read_slice_ref() {
+    let d = [69, 88, 65, 77];
+
+    let (a, b) = {
+        let mut b = Reader::new(&d);
+        (b.read_slice_ref(2).unwrap(), b.read_slice_ref(2).unwrap())
+    };
+
+    assert_eq!(a, &[69, 88]);
+    assert_eq!(b, &[65, 77]);
+}
+
+#[test]
+fn test_read_ref_bytes() {
+    let d = &[0, 2, 69, 88, 65, 77];
+    let mut b = Reader::new(d);
+
+    assert_eq!(u16::read_ref::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(
+        <&[u8]>::read_ref::<BigEndian>(&mut b),
+        Ok([69, 88, 65, 77].as_slice())
+    );
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_read_ref_bytes_prefix() {
+    let d = &[2, 69, 88, 3, 65];
+    let mut b = Reader::new(d);
+    b.set_string_prefix(StringPrefix::U8);
+
+    assert_eq!(
+        <&[u8]>::read_ref::<BigEndian>(&mut b),
+        Ok([69, 88].as_slice())
+    );
+    assert_eq!(
+        <&[u8]>::read_ref::<BigEndian>(&mut b),
+        Err(Error::BufferTooShort)
+    );
+    assert_eq!(b.offset(), 3);
+}
+
+#[test]
+fn test_read_ref_str() {
+    let d = b"\x04gr\xC3\xBC\x02\xC3\x28";
+    let mut b = Reader::new(d);
+    b.set_string_prefix(StringPrefix::U8);
+    b.set_string_policy(StringPolicy::Utf8);
+
+    assert_eq!(<&str>::read_ref::<BigEndian>(&mut b), Ok("grü"));
+    assert_eq!(
+        <&str>::read_ref::<BigEndian>(&mut b),
+        Err(Error::InvalidUtf8 { offset: 6 })
+    );
+
+    // Borrowed strings can't be lossy
+    b.set_string_policy(StringPolicy::Lossy);
+    assert_eq!(
+        <&str>::read_ref::<BigEndian>(&mut b),
+        Err(Error::InvalidUtf8 { offset: 6 })
+    );
+    assert_eq!(
+        Cow::<str>::read_ref::<BigEndian>(&mut b),
+        Ok(Cow::Owned(String::from("\u{FFFD}(")))
+    );
+}
+
+#[test]
+fn test_read_ref_cow_borrowed() {
+    let d = b"example";
+    let mut b = Reader::new(d);
+
+    assert!(matches!(
+        Cow::<str>::read_ref::<BigEndian>(&mut b),
+        Ok(Cow::Borrowed("example"))
+    ));
+}
+
+#[test]
+fn test_read_ref_stream() {
+    let d: &[u8] = &[2, 69, 88];
+    let mut b = Reader::from_reader(d);
+    b.set_string_prefix(StringPrefix::U8);
+
+    assert_eq!(
+        <&[u8]>::read_ref::<BigEndian>(&mut b),
+        Err(Error::BorrowUnsupported)
+    );
+    assert_eq!(b.read_slice_ref(1), Err(Error::BorrowUnsupported));
+    assert_eq!(
+        b.read_cstring_ref(None, Terminator::Optional),
+        Err(Error::BorrowUnsupported)
+    );
+    assert_eq!(b.read_padded_ref(1, 0), Err(Error::BorrowUnsupported));
+    assert_eq!(u8::read_ref::<BigEndian>(&mut b), Ok(2));
+}
@@ -0,0 +1,76 @@
+use binbuf::{read::Reader, BigEndian, LittleEndian, Read, ReadableMulti};
+
+#[test]
+fn test_read_i8() {
+    let b = &[0xFF, 0x80, 0x7F];
+    let mut b = Reader::new(b);
+
+    assert_eq!(i8::read::<BigEndian>(&mut b), Ok(-1));
+    assert_eq!(i8::read::<BigEndian>(&mut b), Ok(i8::MIN));
+    assert_eq!(i8::read::<BigEndian>(&mut b), Ok(i8::MAX));
+}
+
+#[test]
+fn test_read_i16() {
+    let b = &[0xFF, 0xFE, 0xFE, 0xFF];
+    let mut b = Reader::new(b);
+
+    assert_eq!(i16::read::<BigEndian>(&mut b), Ok(-2));
+    assert_eq!(i16::read::<LittleEndian>(&mut b), Ok(-2));
+}
+
+#[test]
+fn test_read_i32() {
+    let b = &[0xFF, 0xFF, 0xFF, 0x85, 0x85, 0xFF, 0xFF, 0xFF];
+    let mut b = Reader::new(b);
+
+    assert_eq!(i32::read::<BigEndian>(&mut b), Ok(-123));
+    assert_eq!(i32::read::<LittleEndian>(&mut b), Ok(-123));
+}
+
+#[test]
+fn test_read_i64() {
+    let b = &[0x80, 0, 0, 0, 0, 0, 0, 0];
+    let mut b = Reader::new(b);
+
+    assert_eq!(i64::read::<BigEndian>(&mut b), Ok(i64::MIN));
+}
+
+#[test]
+fn test_read_i128() {
+    let b = &[0xFF; 16];
+    let mut b = Reader::new(b);
+
+    assert_eq!(i128::read::<LittleEndian>(&mut b), Ok(-1));
+}
+
+#[test]
+fn test_read_multi_i16() {
+    let b = &[0xFF, 0xFF, 0x00, 0x01, 0x80, 0x00];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        i16::read_multi::<BigEndian, 3>(&mut b),
+        Ok([-1, 1, i16::MIN])
+    );
+}
+
+#[test]
+fn test_read_write_signed_roundtrip() {
+    use binbuf::{Write, Writer};
+
+    let mut w = Writer::new();
+    (-5i8).write::<BigEndian>(&mut w).unwrap();
+    (-300i16).write::<LittleEndian>(&mut w).unwrap();
+    (-70000i32).write::<BigEndian>(&mut w).unwrap();
+    i64::MIN.write::<LittleEndian>(&mut w).unwrap();
+    (-1i128).write::<BigEndian>(&mut w).unwrap();
+
+    let mut r = Reader::new(w.bytes());
+    assert_eq!(i8::read::<BigEndian>(&mut r), Ok(-5));
+    assert_eq!(i16::read::<LittleEndian>(&mut r), Ok(-300));
+    assert_eq!(i32::read::<BigEndian>(&mut r), Ok(-70000));
+    assert_eq!(i64::read::<LittleEndian>(&mut r), Ok(i64::MIN));
+    assert_eq!(i128::read::<BigEndian>(&mut r), Ok(-1));
+    assert!(r.is_empty());
+}
@@ -0,0 +1,199 @@
+use std::io;
+
+use binbuf::{read::Error, BigEndian, BitOrder, Read, ReadableMulti, Reader};
+
+/// A source which returns at most one byte per read call.
+struct Trickle<'a>(&'a [u8]);
+
+impl io::Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.0.split_first() {
+            Some((first, rest)) if !buf.is_empty() => {
+                buf[0] = *first;
+                self.0 = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+/// A source which fails after returning its data.
+struct Failing<'a>(&'a [u8]);
+
+impl io::Read for Failing<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset"));
+        }
+
+        let n = io::Read::read(&mut self.0, buf)?;
+        Ok(n)
+    }
+}
+
+#[test]
+fn test_read_stream() {
+    let data = [69, 88, 65, 77, 80, 76, 69, 33];
+    let mut buf = Reader::from_reader(data.as_slice());
+
+    assert_eq!(u16::read::<BigEndian>(&mut buf), Ok(17752));
+    assert_eq!(u16::read::<BigEndian>(&mut buf), Ok(16717));
+    assert_eq!(u32::read::<BigEndian>(&mut buf), Ok(1347175713));
+    assert_eq!(buf.offset(), 8);
+
+    assert_eq!(u8::read::<BigEndian>(&mut buf), Err(Error::BufferTooShort));
+}
+
+#[test]
+fn test_read_stream_refill() {
+    let data = [
+        69, 88, 65, 77, 80, 76, 69, 33, 69, 88, 65, 77, 80, 76, 69, 33,
+    ];
+    let mut buf = Reader::from_reader_with_capacity(3, Trickle(&data));
+
+    assert_eq!(u8::read::<BigEndian>(&mut buf), Ok(69));
+    assert_eq!(u64::read::<BigEndian>(&mut buf), Ok(6359449156096172357));
+    assert_eq!(
+        u16::read_multi::<BigEndian, 3>(&mut buf),
+        Ok([22593, 19792, 19525])
+    );
+    assert_eq!(buf.offset(), 15);
+
+    assert_eq!(buf.pop(), Ok(33));
+    assert_eq!(buf.pop(), Err(Error::BufferTooShort));
+}
+
+#[test]
+fn test_read_stream_too_short() {
+    let data = [69, 88, 65];
+    let mut buf = Reader::from_reader(Trickle(&data));
+
+    assert_eq!(u32::read::<BigEndian>(&mut buf), Err(Error::BufferTooShort));
+
+    // The buffered bytes are still available after the failed read
+    assert_eq!(u16::read::<BigEndian>(&mut buf), Ok(17752));
+}
+
+#[test]
+fn test_read_stream_io_error() {
+    let data = [69, 88];
+    let mut buf = Reader::from_reader(Failing(&data));
+
+    assert_eq!(u16::read::<BigEndian>(&mut buf), Ok(17752));
+    assert_eq!(
+        u16::read::<BigEndian>(&mut buf),
+        Err(Error::Io {
+            kind: io::ErrorKind::ConnectionReset,
+            message: String::from("reset")
+        })
+    );
+}
+
+#[test]
+fn test_read_stream_peek() {
+    let data = [69, 88, 65, 77];
+    let mut buf = Reader::from_reader(data.as_slice());
+
+    assert_eq!(buf.peek(), None);
+    assert!(buf.is_empty());
+
+    buf.fill(2).unwrap();
+    assert_eq!(buf.peekn::<2>(), Some([69, 88]));
+    assert_eq!(buf.len(), 4);
+
+    assert_eq!(buf.fill(5), Err(Error::BufferTooShort));
+}
+
+#[test]
+fn test_read_stream_char_string() {
+    let data = [4, 88, 65, 77, 80, 76];
+    let mut buf = Reader::from_reader(Trickle(&data));
+
+    assert_eq!(buf.read_char_string(None), Ok([88, 65, 77, 80].as_slice()));
+    assert_eq!(buf.read_char_string(None), Err(Error::BufferTooShort));
+}
+
+#[test]
+fn test_read_stream_jump() {
+    let data = [3, 99, 111, 109, 0, 0xC0, 0];
+    let mut buf = Reader::from_reader(data.as_slice());
+
+    buf.skipn(2).unwrap();
+    assert_eq!(buf.jump_to(0), Err(Error::JumpUnsupported));
+
+    buf.reset();
+    assert_eq!(buf.offset(), 2);
+}
+
+#[test]
+fn test_read_stream_domain_name() {
+    let data = [3, 99, 111, 109, 0, 0xC0, 0];
+    let mut buf = Reader::from_reader(Trickle(&data));
+
+    assert_eq!(buf.read_domain_name(), Ok(vec![b"com".to_vec()]));
+    assert_eq!(buf.read_domain_name(), Err(Error::JumpUnsupported));
+}
+
+#[test]
+fn test_read_stream_bits() {
+    let data = [0x45, 0x00, 0x00, 0x54];
+    let mut buf = Reader::from_reader(Trickle(&data));
+
+    let mut bits = buf.bits(BitOrder::MsbFirst);
+    assert_eq!(bits.read_bits(4), Ok(4));
+    assert_eq!(bits.read_bits(12), Ok(0x500));
+    assert_eq!(
+        bits.read_bits(17),
+        Err(Error::BitBufferTooShort {
+            nbits: 17,
+            offset: 16
+        })
+    );
+
+    assert_eq!(u16::read::<BigEndian>(&mut buf), Ok(84));
+}
+
+#[test]
+fn test_read_stream_fill_large() {
+    // Filling must not allocate the requested amount of bytes upfront
+    let data = [69, 88, 65, 77];
+    let mut buf = Reader::from_reader(&data[..]);
+
+    assert_eq!(buf.fill(u32::MAX as usize), Err(Error::BufferTooShort));
+    assert_eq!(buf.len(), 4);
+}
+
+#[test]
+fn test_read_stream_rewind_discarded() {
+    // Reading the body discards the length prefix from the refill buffer,
+    // which means the cursor cannot be moved back to the prefix
+    let data = [3, 69, 88];
+    let mut buf = Reader::from_reader_with_capacity(1, &data[..]);
+
+    assert_eq!(
+        buf.read_length_prefixed::<u8, BigEndian>(None),
+        Err(Error::RewindUnsupported { offset: 0 })
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_read_stream_derive() {
+    use std::net::Ipv4Addr;
+
+    #[derive(Read)]
+    struct Data {
+        v1: u16,
+        v2: u16,
+        v3: Ipv4Addr,
+    }
+
+    let data = [69, 88, 65, 77, 80, 76, 69, 33];
+    let mut buf = Reader::from_reader(Trickle(&data));
+
+    let data = Data::read::<BigEndian>(&mut buf).unwrap();
+    assert_eq!(data.v1, 17752);
+    assert_eq!(data.v2, 16717);
+    assert_eq!(data.v3, Ipv4Addr::new(80, 76, 69, 33));
+}
@@ -0,0 +1,106 @@
+use binbuf::{
+    read::Error,
+    string::{StringPolicy, StringPrefix},
+    BigEndian, LittleEndian, Read, Reader,
+};
+
+#[test]
+fn test_read_str_ascii() {
+    let b = b"root\xC3\xBC";
+    let mut b = Reader::new(b);
+
+    assert_eq!(b.read_str(4, StringPolicy::Ascii), Ok("root".into()));
+    assert_eq!(
+        b.read_str(2, StringPolicy::Ascii),
+        Err(Error::NonAsciiData { offset: 4 })
+    );
+    assert_eq!(b.offset(), 4);
+}
+
+#[test]
+fn test_read_str_utf8() {
+    let b = b"gr\xC3\xBC\xC3";
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_str(5, StringPolicy::Utf8),
+        Err(Error::InvalidUtf8 { offset: 4 })
+    );
+    assert_eq!(b.read_str(4, StringPolicy::Utf8), Ok("grü".into()));
+    assert_eq!(
+        b.read_str(2, StringPolicy::Utf8),
+        Err(Error::BufferTooShort)
+    );
+}
+
+#[test]
+fn test_read_str_lossy() {
+    let b = b"gr\xFFn";
+    let mut b = Reader::new(b);
+
+    assert_eq!(b.read_str(4, StringPolicy::Lossy), Ok("gr\u{FFFD}n".into()));
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_read_str_prefixed() {
+    let b = &[0, 3, 115, 115, 104, 2, 0xC3, 0xBC];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        b.read_str_prefixed::<u16, BigEndian>(Some(3), StringPolicy::Ascii),
+        Ok("ssh".into())
+    );
+    assert_eq!(
+        b.read_str_prefixed::<u8, BigEndian>(None, StringPolicy::Ascii),
+        Err(Error::NonAsciiData { offset: 6 })
+    );
+    assert_eq!(b.offset(), 5);
+    assert_eq!(
+        b.read_str_prefixed::<u8, BigEndian>(None, StringPolicy::Utf8),
+        Ok("ü".into())
+    );
+}
+
+#[test]
+fn test_read_string_default() {
+    let b = b"example";
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        String::read::<BigEndian>(&mut b),
+        Ok(String::from("example"))
+    );
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_read_string_prefix() {
+    let b = &[2, 0, 0, 0, 0xC3, 0xBC, 1, 0, 0, 0, 0xFF];
+    let mut b = Reader::new(b);
+    b.set_string_prefix(StringPrefix::U32);
+    b.set_string_policy(StringPolicy::Utf8);
+
+    assert_eq!(String::read::<LittleEndian>(&mut b), Ok(String::from("ü")));
+    assert_eq!(
+        String::read::<LittleEndian>(&mut b),
+        Err(Error::InvalidUtf8 { offset: 10 })
+    );
+
+    b.set_string_policy(StringPolicy::Lossy);
+    assert_eq!(
+        String::read::<LittleEndian>(&mut b),
+        Ok(String::from("\u{FFFD}"))
+    );
+}
+
+#[test]
+fn test_read_string_stream() {
+    let d: &[u8] = b"streamed string";
+    let mut b = Reader::from_reader_with_capacity(2, d);
+
+    assert_eq!(
+        String::read::<BigEndian>(&mut b),
+        Ok(String::from("streamed string"))
+    );
+}
@@ -0,0 +1,177 @@
+use binbuf::{
+    read::{Error, Reader},
+    varint::{QuicVarInt, Sleb128, Uleb128, ZigZag},
+    BigEndian, Read, ReadableMulti,
+};
+
+#[test]
+fn test_read_uleb128() {
+    let b = &[0x00, 0x7F, 0xE5, 0x8E, 0x26];
+    let mut b = Reader::new(b);
+
+    assert_eq!(Uleb128::read::<BigEndian>(&mut b), Ok(Uleb128(0)));
+    assert_eq!(Uleb128::read::<BigEndian>(&mut b), Ok(Uleb128(127)));
+    assert_eq!(Uleb128::read::<BigEndian>(&mut b), Ok(Uleb128(624485)));
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_read_uleb128_max() {
+    let b = &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01];
+    let mut b = Reader::new(b);
+
+    assert_eq!(Uleb128::read::<BigEndian>(&mut b), Ok(Uleb128(u64::MAX)));
+}
+
+#[test]
+fn test_read_uleb128_overlong() {
+    let b = &[0xE5, 0x8E, 0xA6, 0x00];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        Uleb128::read::<BigEndian>(&mut b),
+        Err(Error::OverlongVarInt)
+    );
+    assert_eq!(b.offset(), 0);
+}
+
+#[test]
+fn test_read_uleb128_overflow() {
+    let b = &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        Uleb128::read::<BigEndian>(&mut b),
+        Err(Error::VarIntOverflow { bits: 64 })
+    );
+
+    let b = &[0x80; 11];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        Uleb128::read::<BigEndian>(&mut b),
+        Err(Error::VarIntOverflow { bits: 64 })
+    );
+    assert_eq!(b.offset(), 0);
+}
+
+#[test]
+fn test_read_uleb128_too_short() {
+    let b = &[0xE5, 0x8E];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        Uleb128::read::<BigEndian>(&mut b),
+        Err(Error::BufferTooShort)
+    );
+    assert_eq!(b.offset(), 0);
+}
+
+#[test]
+fn test_read_uleb128_stream() {
+    let d: &[u8] = &[0xE5, 0x8E, 0x26, 0x01];
+    let mut b = Reader::from_reader_with_capacity(1, d);
+
+    assert_eq!(
+        Uleb128::read_multi::<BigEndian, 2>(&mut b),
+        Ok([Uleb128(624485), Uleb128(1)])
+    );
+}
+
+#[test]
+fn test_read_sleb128() {
+    let b = &[0x7F, 0x3F, 0xC0, 0x00, 0x40, 0xBF, 0x7F, 0xC0, 0xBB, 0x78];
+    let mut b = Reader::new(b);
+
+    assert_eq!(Sleb128::read::<BigEndian>(&mut b), Ok(Sleb128(-1)));
+    assert_eq!(Sleb128::read::<BigEndian>(&mut b), Ok(Sleb128(63)));
+    assert_eq!(Sleb128::read::<BigEndian>(&mut b), Ok(Sleb128(64)));
+    assert_eq!(Sleb128::read::<BigEndian>(&mut b), Ok(Sleb128(-64)));
+    assert_eq!(Sleb128::read::<BigEndian>(&mut b), Ok(Sleb128(-65)));
+    assert_eq!(Sleb128::read::<BigEndian>(&mut b), Ok(Sleb128(-123456)));
+}
+
+#[test]
+fn test_read_sleb128_limits() {
+    let b = &[
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, // i64::MIN
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, // i64::MAX
+    ];
+    let mut b = Reader::new(b);
+
+    assert_eq!(Sleb128::read::<BigEndian>(&mut b), Ok(Sleb128(i64::MIN)));
+    assert_eq!(Sleb128::read::<BigEndian>(&mut b), Ok(Sleb128(i64::MAX)));
+}
+
+#[test]
+fn test_read_sleb128_invalid() {
+    for b in [&[0xFF, 0x7F][..], &[0x80, 0x00], &[0xC0, 0xFF, 0x7F]] {
+        let mut b = Reader::new(b);
+        assert_eq!(
+            Sleb128::read::<BigEndian>(&mut b),
+            Err(Error::OverlongVarInt)
+        );
+    }
+
+    let b = &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x40];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        Sleb128::read::<BigEndian>(&mut b),
+        Err(Error::VarIntOverflow { bits: 64 })
+    );
+}
+
+#[test]
+fn test_read_zigzag() {
+    let b = &[0x00, 0x01, 0x02, 0x03, 0xAC, 0x02];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        ZigZag::read_multi::<BigEndian, 5>(&mut b),
+        Ok([ZigZag(0), ZigZag(-1), ZigZag(1), ZigZag(-2), ZigZag(150)])
+    );
+}
+
+#[test]
+fn test_read_quic_varint() {
+    // Examples from RFC 9000, appendix A.1
+    let b = &[
+        0xC2, 0x19, 0x7C, 0x5E, 0xFF, 0x14, 0xE8, 0x8C, // 8 bytes
+        0x9D, 0x7F, 0x3E, 0x7D, // 4 bytes
+        0x7B, 0xBD, // 2 bytes
+        0x25, // 1 byte
+    ];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        QuicVarInt::read::<BigEndian>(&mut b),
+        Ok(QuicVarInt(151288809941952652))
+    );
+    assert_eq!(
+        QuicVarInt::read::<BigEndian>(&mut b),
+        Ok(QuicVarInt(494878333))
+    );
+    assert_eq!(QuicVarInt::read::<BigEndian>(&mut b), Ok(QuicVarInt(15293)));
+    assert_eq!(QuicVarInt::read::<BigEndian>(&mut b), Ok(QuicVarInt(37)));
+}
+
+#[test]
+fn test_read_quic_varint_invalid() {
+    // Overlong encodings are valid QUIC, RFC 9000, appendix A.1
+    let b = &[0x40, 0x25];
+    let mut b = Reader::new(b);
+
+    assert_eq!(QuicVarInt::read_strict(&mut b), Err(Error::OverlongVarInt));
+    assert_eq!(b.offset(), 0);
+    assert_eq!(QuicVarInt::read::<BigEndian>(&mut b), Ok(QuicVarInt(37)));
+
+    let b = &[0x9D, 0x7F, 0x3E];
+    let mut b = Reader::new(b);
+
+    assert_eq!(
+        QuicVarInt::read::<BigEndian>(&mut b),
+        Err(Error::BufferTooShort)
+    );
+    assert_eq!(b.offset(), 0);
+}
@@ -0,0 +1,106 @@
+use std::net::Ipv4Addr;
+
+use binbuf::{
+    read::Error,
+    tokio::{AsyncReader, AsyncWriter},
+    write::WriteError,
+    BigEndian, LittleEndian,
+};
+use tokio::io::{duplex, AsyncWriteExt};
+
+#[tokio::test]
+async fn test_async_roundtrip() {
+    let (client, server) = duplex(64);
+    let mut w = AsyncWriter::new(client);
+    let mut r = AsyncReader::new(server);
+
+    assert_eq!(w.write_be(&17752u16).await, Ok(2));
+    assert_eq!(w.write_le(&1095585868u32).await, Ok(4));
+    assert_eq!(w.write_be(&Ipv4Addr::LOCALHOST).await, Ok(4));
+
+    assert_eq!(r.read_be::<u16>().await, Ok(17752));
+    assert_eq!(r.read::<u32, LittleEndian>().await, Ok(1095585868));
+    assert_eq!(
+        r.read::<Ipv4Addr, BigEndian>().await,
+        Ok(Ipv4Addr::LOCALHOST)
+    );
+    assert!(r.buffer().is_empty());
+}
+
+#[tokio::test]
+async fn test_async_read_split_chunks() {
+    let (mut client, server) = duplex(64);
+    let mut r = AsyncReader::with_capacity(1, server);
+
+    let handle = tokio::spawn(async move {
+        for b in [69, 88, 65, 77, 80, 76, 69, 33] {
+            client.write_all(&[b]).await.unwrap();
+            tokio::task::yield_now().await;
+        }
+    });
+
+    assert_eq!(r.read_be::<u64>().await, Ok(4996815586883028257));
+    handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_async_read_eof() {
+    let d: &[u8] = &[69, 88, 65];
+    let mut r = AsyncReader::new(d);
+
+    assert_eq!(r.read_be::<u16>().await, Ok(17752));
+    assert_eq!(r.read_be::<u16>().await, Err(Error::BufferTooShort));
+
+    // Failed reads don't consume any bytes
+    assert_eq!(r.buffer(), &[65]);
+    assert_eq!(r.read_be::<u8>().await, Ok(65));
+}
+
+#[tokio::test]
+async fn test_async_write_error() {
+    let (client, server) = duplex(64);
+    drop(server);
+
+    let mut w = AsyncWriter::new(client);
+    let err = w.write_be(&17752u16).await.unwrap_err();
+    assert!(matches!(err, WriteError::Io { .. }));
+}
+
+#[tokio::test]
+async fn test_async_write_float_policy() {
+    use binbuf::write::FloatPolicy;
+
+    let mut w = AsyncWriter::new(Vec::new());
+    w.set_float_policy(FloatPolicy::Reject);
+
+    assert_eq!(w.write_be(&-0.0f32).await, Err(WriteError::NegativeZero));
+    assert!(w.get_ref().is_empty());
+}
+
+#[tokio::test]
+async fn test_async_read_string_settings() {
+    use binbuf::string::{StringPolicy, StringPrefix};
+
+    let d: &[u8] = &[4, 0xC3, 0xA4, 0x62, 0x63, 255, 255, 255, 255];
+    let mut r = AsyncReader::new(d);
+    r.set_string_policy(StringPolicy::Utf8);
+    r.set_string_prefix(StringPrefix::U8);
+    r.set_length_limit(1024);
+
+    assert_eq!(r.read_be::<String>().await, Ok(String::from("äbc")));
+
+    r.set_string_prefix(StringPrefix::U32);
+    assert_eq!(r.read_be::<String>().await, Err(Error::MaxLengthOverflow));
+}
+
+#[test]
+fn test_async_futures_are_send() {
+    fn assert_send<T: Send>(_: &T) {}
+
+    let (client, server) = duplex(64);
+    let mut w = AsyncWriter::new(client);
+    let mut r = AsyncReader::new(server);
+
+    assert_send(&w.write_be(&17752u16));
+    assert_send(&r.read_be::<u16>());
+}
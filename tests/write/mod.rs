@@ -5,12 +5,23 @@ use binbuf::{
     BigEndian, Write,
 };
 
+mod write_bits;
 mod write_buffer;
+mod write_cstring;
 mod write_derive;
+mod write_derive_enum;
+mod write_domain_name;
+mod write_float;
 mod write_impl;
+mod write_length;
+mod write_length_prefixed;
 mod write_macro;
 mod write_multi;
+mod write_signed;
+mod write_sink;
 mod write_span;
+mod write_string;
+mod write_varint;
 
 #[test]
 fn write_u8() {
@@ -0,0 +1,120 @@
+use binbuf::{
+    write::{WriteError, Writer},
+    BitOrder, Reader,
+};
+
+#[test]
+fn test_write_bits_ipv4_header() {
+    let mut b = Writer::new();
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    bits.write_bits(4, 4).unwrap();
+    bits.write_bits(5, 4).unwrap();
+    bits.write_bits(0, 6).unwrap();
+    bits.write_bits(0, 2).unwrap();
+
+    assert_eq!(bits.finish(), Ok(2));
+    assert_eq!(b.bytes(), &[0x45, 0x00]);
+}
+
+#[test]
+fn test_write_bits_lsb_first() {
+    let mut b = Writer::new();
+
+    let mut bits = b.bits(BitOrder::LsbFirst);
+    bits.write_flag(false).unwrap();
+    bits.write_bits(0b101, 3).unwrap();
+    bits.write_bits(0b1100_1010, 8).unwrap();
+    bits.write_bits(0b1100, 4).unwrap();
+
+    assert_eq!(bits.finish(), Ok(2));
+    assert_eq!(b.bytes(), &[0b1010_1010, 0b1100_1100]);
+}
+
+#[test]
+fn test_write_bits_64() {
+    let mut b = Writer::new();
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    bits.write_bits(0x0123456789ABCDEF, 64).unwrap();
+    assert_eq!(bits.finish(), Ok(8));
+
+    assert_eq!(b.bytes(), &[0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF]);
+}
+
+#[test]
+fn test_write_bits_roundtrip() {
+    let fields = [(1, 1), (0x1F, 5), (0, 3), (0x2A5, 10), (7, 3), (0xBEEF, 16)];
+
+    for order in [BitOrder::MsbFirst, BitOrder::LsbFirst] {
+        let mut b = Writer::new();
+
+        let mut bits = b.bits(order);
+        for (value, nbits) in fields {
+            bits.write_bits(value, nbits).unwrap();
+        }
+        assert_eq!(bits.finish_padded(), Ok(5));
+
+        let mut r = Reader::new(b.bytes());
+        let mut bits = r.bits(order);
+        for (value, nbits) in fields {
+            assert_eq!(bits.read_bits(nbits), Ok(value));
+        }
+    }
+}
+
+#[test]
+fn test_write_bits_unaligned() {
+    let mut b = Writer::new();
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    bits.write_bits(0xFF, 8).unwrap();
+    bits.write_bits(1, 3).unwrap();
+
+    assert_eq!(bits.finish(), Err(WriteError::UnalignedBits { nbits: 5 }));
+    assert_eq!(b.bytes(), &[0xFF]);
+}
+
+#[test]
+fn test_write_bits_padded() {
+    let mut b = Writer::new();
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    bits.write_bits(1, 3).unwrap();
+    assert!(!bits.is_aligned());
+
+    assert_eq!(bits.finish_padded(), Ok(1));
+    assert_eq!(b.bytes(), &[0b0010_0000]);
+}
+
+#[test]
+fn test_write_bits_value_overflow() {
+    let mut b = Writer::new();
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    assert_eq!(
+        bits.write_bits(16, 4),
+        Err(WriteError::BitValueOverflow {
+            value: 16,
+            nbits: 4
+        })
+    );
+    assert_eq!(
+        bits.write_bits(0, 65),
+        Err(WriteError::BitCountOverflow { nbits: 65 })
+    );
+    assert!(bits.is_aligned());
+}
+
+#[test]
+fn test_write_bits_span() {
+    let mut b = Writer::new();
+    b.enter();
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    bits.write_bits(0x3FF, 10).unwrap();
+    bits.align().unwrap();
+
+    b.push(69);
+    assert_eq!(b.exit(), 3);
+}
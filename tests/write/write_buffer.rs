@@ -45,3 +45,143 @@ fn test_write_buffer_with_clear() {
     assert_eq!(buf.len(), 0);
     assert_eq!(buf.bytes(), &[]);
 }
+
+#[test]
+fn test_fixed_write_buffer() {
+    use binbuf::{BigEndian, Write};
+    use std::net::Ipv4Addr;
+
+    let mut data = [0u8; 8];
+    let mut buf = Writer::new_fixed(&mut data);
+
+    assert_eq!(17752u16.write::<BigEndian>(&mut buf), Ok(2));
+    assert_eq!(16717u16.write::<BigEndian>(&mut buf), Ok(2));
+    assert_eq!(
+        Ipv4Addr::new(80, 76, 69, 33).write::<BigEndian>(&mut buf),
+        Ok(4)
+    );
+
+    assert_eq!(buf.len(), 8);
+    assert_eq!(buf.remaining(), Some(0));
+    assert_eq!(buf.bytes(), &[69, 88, 65, 77, 80, 76, 69, 33]);
+
+    drop(buf);
+    assert_eq!(data, [69, 88, 65, 77, 80, 76, 69, 33]);
+}
+
+#[test]
+fn test_fixed_write_buffer_full() {
+    use binbuf::{write::WriteError, BigEndian, Write};
+
+    let mut data = [0u8; 3];
+    let mut buf = Writer::new_fixed(&mut data);
+
+    buf.push(69);
+
+    assert_eq!(
+        1163411789u32.write::<BigEndian>(&mut buf),
+        Err(WriteError::BufferFull {
+            nbytes: 4,
+            remaining: 2
+        })
+    );
+
+    // The failed write must not write any bytes
+    assert_eq!(buf.bytes(), &[69]);
+    assert_eq!(buf.remaining(), Some(2));
+
+    // Calling write_be directly returns the error as well
+    assert_eq!(
+        1163411789u32.write_be(&mut buf),
+        Err(WriteError::BufferFull {
+            nbytes: 4,
+            remaining: 2
+        })
+    );
+    assert_eq!(buf.take_error(), None);
+}
+
+#[test]
+fn test_fixed_write_buffer_pending_error() {
+    use binbuf::{write::WriteError, BigEndian, Write};
+
+    let mut data = [0u8; 2];
+    let mut buf = Writer::new_fixed(&mut data);
+
+    // The infallible write keeps the error until it is retrieved
+    assert_eq!(buf.write([69, 88, 65]), 0);
+    assert_eq!(buf.len(), 0);
+    assert_eq!(
+        buf.take_error(),
+        Some(WriteError::BufferFull {
+            nbytes: 3,
+            remaining: 2
+        })
+    );
+    assert_eq!(buf.take_error(), None);
+
+    // A pending error is returned by the next fallible write
+    buf.push(69);
+    buf.push(88);
+    buf.push(65);
+    assert_eq!(
+        buf.try_write([77]),
+        Err(WriteError::BufferFull {
+            nbytes: 1,
+            remaining: 0
+        })
+    );
+    assert_eq!(buf.try_write([]), Ok(0));
+
+    buf.clear();
+    assert_eq!(17752u16.write::<BigEndian>(&mut buf), Ok(2));
+    assert_eq!(buf.bytes(), &[69, 88]);
+}
+
+#[test]
+fn test_fixed_write_buffer_clear() {
+    let mut data = [0u8; 2];
+    let mut buf = Writer::new_fixed(&mut data);
+
+    buf.write([69, 88]);
+    assert_eq!(buf.remaining(), Some(0));
+
+    buf.clear();
+
+    assert_eq!(buf.len(), 0);
+    assert_eq!(buf.remaining(), Some(2));
+    assert_eq!(buf.bytes(), &[]);
+}
+
+#[test]
+fn test_fixed_write_buffer_length() {
+    use binbuf::BigEndian;
+
+    let mut data = [0u8; 16];
+    let mut buf = Writer::new_fixed(&mut data);
+
+    let slot = buf.reserve_length::<u16, BigEndian>().unwrap();
+    buf.write([69, 88, 65]);
+
+    assert_eq!(buf.patch_length(slot), Ok(3));
+    assert_eq!(buf.bytes(), &[0, 3, 69, 88, 65]);
+}
+
+#[test]
+fn test_fixed_write_buffer_bits() {
+    use binbuf::{write::WriteError, BitOrder};
+
+    let mut data = [0u8; 1];
+    let mut buf = Writer::new_fixed(&mut data);
+
+    let mut bits = buf.bits(BitOrder::MsbFirst);
+    bits.write_bits(0x45, 8).unwrap();
+
+    assert_eq!(
+        bits.write_bits(1, 8),
+        Err(WriteError::BufferFull {
+            nbytes: 1,
+            remaining: 0
+        })
+    );
+}
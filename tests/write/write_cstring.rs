@@ -0,0 +1,54 @@
+use binbuf::write::{WriteError, Writer};
+
+#[test]
+fn test_write_cstring() {
+    let mut b = Writer::new();
+
+    assert_eq!(b.write_cstring("pxelinux.0", None), Ok(11));
+    assert_eq!(b.write_cstring([], None), Ok(1));
+    assert_eq!(b.bytes(), b"pxelinux.0\0\0");
+}
+
+#[test]
+fn test_write_cstring_errors() {
+    let mut b = Writer::new();
+
+    assert_eq!(
+        b.write_cstring("boot", Some(3)),
+        Err(WriteError::MaxLengthOverflow)
+    );
+    assert_eq!(
+        b.write_cstring("bo\0ot", None),
+        Err(WriteError::InteriorNul { offset: 2 })
+    );
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_write_cstring_fixed_buffer() {
+    let mut buf = [0u8; 4];
+    let mut b = Writer::new_fixed(&mut buf);
+
+    assert_eq!(
+        b.write_cstring("boot", None),
+        Err(WriteError::BufferFull {
+            nbytes: 5,
+            remaining: 4
+        })
+    );
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_write_padded() {
+    let mut b = Writer::new();
+
+    assert_eq!(b.write_padded("tftp", 6, 0), Ok(6));
+    assert_eq!(b.write_padded("root", 4, b' '), Ok(4));
+    assert_eq!(b.bytes(), b"tftp\0\0root");
+
+    assert_eq!(
+        b.write_padded("tftp", 3, 0),
+        Err(WriteError::MaxLengthOverflow)
+    );
+}
@@ -81,3 +81,255 @@ fn test_writeable_impl_derive_nested() {
         Err(err) => panic!("{}", err),
     }
 }
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_signed_fields() {
+    use binbuf::{LittleEndian, Write, Writer};
+
+    #[derive(Write)]
+    struct Data {
+        v1: i8,
+        v2: i16,
+        v3: i64,
+    }
+
+    let d = Data {
+        v1: -1,
+        v2: -2,
+        v3: i64::MIN,
+    };
+    let mut b = Writer::new();
+
+    assert_eq!(d.write::<LittleEndian>(&mut b), Ok(11));
+    assert_eq!(b.bytes(), &[0xFF, 0xFE, 0xFF, 0, 0, 0, 0, 0, 0, 0, 0x80]);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_varint_fields() {
+    use binbuf::{
+        varint::{Sleb128, Uleb128},
+        BigEndian, Write, Writer,
+    };
+
+    #[derive(Write)]
+    struct Data {
+        len: Uleb128,
+        offset: Sleb128,
+    }
+
+    let d = Data {
+        len: Uleb128(624485),
+        offset: Sleb128(-65),
+    };
+    let mut b = Writer::new();
+
+    assert_eq!(d.write::<BigEndian>(&mut b), Ok(5));
+    assert_eq!(b.bytes(), &[0xE5, 0x8E, 0x26, 0xBF, 0x7F]);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_string_fields() {
+    use binbuf::{write::WriteError, BigEndian, Write, Writer};
+
+    #[derive(Write)]
+    struct Data {
+        #[binbuf(padded = 6, pad = 0x20)]
+        sname: String,
+        #[binbuf(cstring, max_len = 4)]
+        file: Vec<u8>,
+    }
+
+    let d = Data {
+        sname: String::from("tftp"),
+        file: b"boot".to_vec(),
+    };
+    let mut b = Writer::new();
+
+    assert_eq!(d.write::<BigEndian>(&mut b), Ok(11));
+    assert_eq!(b.bytes(), b"tftp  boot\0");
+
+    let d = Data {
+        sname: String::from("tftp"),
+        file: b"pxelinux.0".to_vec(),
+    };
+    assert_eq!(
+        d.write::<BigEndian>(&mut b),
+        Err(WriteError::MaxLengthOverflow)
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_borrowed_fields() {
+    use binbuf::{string::StringPrefix, BigEndian, Write, Writer};
+
+    #[derive(Write)]
+    struct Packet<'a> {
+        id: u16,
+        name: &'a str,
+        payload: &'a [u8],
+    }
+
+    let payload = vec![88, 65, 77];
+    let d = Packet {
+        id: 69,
+        name: "ping",
+        payload: &payload,
+    };
+
+    let mut b = Writer::new();
+    b.set_string_prefix(StringPrefix::U8);
+
+    assert_eq!(d.write::<BigEndian>(&mut b), Ok(11));
+    assert_eq!(b.bytes(), &[0, 69, 4, 112, 105, 110, 103, 3, 88, 65, 77]);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_tuple_structs() {
+    use binbuf::{BigEndian, Write, Writer};
+
+    #[derive(Write)]
+    struct Ttl(u32);
+
+    #[derive(Write)]
+    struct Marker;
+
+    #[derive(Write)]
+    struct Record(
+        Ttl,
+        Marker,
+        #[binbuf(skip_write = true)] u8,
+        #[binbuf(cstring)] String,
+    );
+
+    let d = Record(Ttl(3600), Marker, 69, String::from("dns"));
+    let mut b = Writer::new();
+
+    assert_eq!(Marker.write::<BigEndian>(&mut b), Ok(0));
+    assert_eq!(d.write::<BigEndian>(&mut b), Ok(8));
+    assert_eq!(b.bytes(), &[0, 0, 14, 16, 100, 110, 115, 0]);
+    assert_eq!(d.2, 69);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_generics() {
+    use std::marker::PhantomData;
+
+    use binbuf::{BigEndian, Write, Writer};
+
+    #[derive(Write)]
+    struct Packet<'a, T> {
+        id: u8,
+        body: T,
+        name: &'a str,
+    }
+
+    struct Tcp;
+
+    #[derive(Write)]
+    #[binbuf(write_bound = "T: Write")]
+    struct Tagged<T, P> {
+        value: T,
+        #[binbuf(skip_write = true)]
+        protocol: PhantomData<P>,
+    }
+
+    let mut b = Writer::new();
+
+    let packet = Packet {
+        id: 1,
+        body: 80u16,
+        name: "tcp",
+    };
+    assert_eq!(packet.write::<BigEndian>(&mut b), Ok(6));
+
+    let tagged = Tagged::<_, Tcp> {
+        value: Packet {
+            id: 2,
+            body: 53u16,
+            name: "",
+        },
+        protocol: PhantomData,
+    };
+    assert_eq!(tagged.write::<BigEndian>(&mut b), Ok(3));
+    assert_eq!(b.bytes(), &[1, 0, 80, 116, 99, 112, 2, 0, 53]);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_endianness() {
+    use binbuf::{write::WriteError, BigEndian, LittleEndian, Write, Writer};
+
+    #[derive(Write)]
+    #[binbuf(endianness = "little")]
+    struct Header {
+        id: u16,
+    }
+
+    #[derive(Write)]
+    #[binbuf(endianness = "big", repr = "u16")]
+    enum Kind {
+        A,
+    }
+
+    let mut b = Writer::new();
+
+    assert_eq!(
+        Header { id: 1 }.write::<BigEndian>(&mut b),
+        Err(WriteError::BigEndianNotSupported)
+    );
+    assert_eq!(Header { id: 1 }.write::<LittleEndian>(&mut b), Ok(2));
+    assert_eq!(
+        Kind::A.write::<LittleEndian>(&mut b),
+        Err(WriteError::LittleEndianNotSupported)
+    );
+    assert_eq!(Kind::A.write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(b.bytes(), &[1, 0, 0, 0]);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_byte_order_overrides() {
+    use binbuf::{BigEndian, Write, Writer};
+
+    #[derive(Write)]
+    #[binbuf(little)]
+    struct Header {
+        magic: u32,
+        #[binbuf(big)]
+        port: u16,
+    }
+
+    #[derive(Write)]
+    #[binbuf(repr = "u16", little)]
+    #[repr(u16)]
+    enum Message {
+        Data {
+            len: u16,
+            #[binbuf(big)]
+            seq: u16,
+        } = 1,
+    }
+
+    let mut b = Writer::new();
+
+    let header = Header {
+        magic: 0xA1B2C3D4,
+        port: 53,
+    };
+
+    assert_eq!(header.write::<BigEndian>(&mut b), Ok(6));
+    assert_eq!(
+        Message::Data { len: 2, seq: 3 }.write::<BigEndian>(&mut b),
+        Ok(6)
+    );
+    assert_eq!(
+        b.bytes(),
+        &[0xD4, 0xC3, 0xB2, 0xA1, 0, 53, 1, 0, 2, 0, 0, 3]
+    );
+}
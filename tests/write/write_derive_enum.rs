@@ -0,0 +1,211 @@
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_enum_simple() {
+    use binbuf::{BigEndian, Write, Writer};
+
+    #[derive(Write)]
+    enum Code {
+        Request,
+        Reply,
+    }
+
+    let mut b = Writer::new();
+
+    assert_eq!(Code::Reply.write::<BigEndian>(&mut b), Ok(1));
+    assert_eq!(Code::Request.write::<BigEndian>(&mut b), Ok(1));
+    assert_eq!(b.bytes(), &[1, 0]);
+    assert_eq!(u8::from(Code::Reply), 1);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_enum_repr() {
+    use binbuf::{BigEndian, LittleEndian, Write, Writer};
+
+    #[derive(Write)]
+    #[binbuf(repr = "u16")]
+    enum Opcode {
+        Query,
+        IQuery,
+        Status,
+    }
+
+    let mut b = Writer::new();
+
+    assert_eq!(Opcode::Status.write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(Opcode::IQuery.write::<LittleEndian>(&mut b), Ok(2));
+    assert_eq!(b.bytes(), &[0, 2, 1, 0]);
+    assert_eq!(u16::from(Opcode::Query), 0);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_enum_roundtrip() {
+    use binbuf::{BigEndian, Read, Reader, Write, Writer};
+
+    #[derive(Debug, PartialEq, Read, Write)]
+    #[binbuf(repr = "i32")]
+    enum Kind {
+        A,
+        B,
+        C,
+    }
+
+    #[derive(Debug, PartialEq, Read, Write)]
+    struct Message {
+        kind: Kind,
+        len: u8,
+    }
+
+    let m = Message {
+        kind: Kind::C,
+        len: 69,
+    };
+    let mut b = Writer::new();
+
+    assert_eq!(m.write::<BigEndian>(&mut b), Ok(5));
+    assert_eq!(b.bytes(), &[0, 0, 0, 2, 69]);
+
+    let mut r = Reader::new(b.bytes());
+    assert_eq!(Message::read::<BigEndian>(&mut r), Ok(m));
+    assert_eq!(Kind::try_from(1), Ok(Kind::B));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_enum_other_variant() {
+    use binbuf::{BigEndian, Read, Reader, Write, Writer};
+
+    #[derive(Debug, PartialEq, Read, Write)]
+    #[binbuf(repr = "u16")]
+    #[repr(u16)]
+    enum EtherType {
+        Ipv4 = 0x0800,
+        Arp = 0x0806,
+        Ipv6 = 0x86DD,
+        #[binbuf(other)]
+        Unknown(u16),
+    }
+
+    let mut b = Writer::new();
+
+    assert_eq!(EtherType::Ipv6.write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(EtherType::Unknown(0x88CC).write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(b.bytes(), &[0x86, 0xDD, 0x88, 0xCC]);
+    assert_eq!(u16::from(EtherType::Arp), 0x0806);
+
+    let mut r = Reader::new(b.bytes());
+    assert_eq!(EtherType::read::<BigEndian>(&mut r), Ok(EtherType::Ipv6));
+    assert_eq!(
+        EtherType::read::<BigEndian>(&mut r),
+        Ok(EtherType::Unknown(0x88CC))
+    );
+    assert_eq!(EtherType::try_from(0x0800), Ok(EtherType::Ipv4));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_data_variants() {
+    use binbuf::{BigEndian, Read, Reader, Write, Writer};
+
+    #[derive(Debug, PartialEq, Read, Write)]
+    #[binbuf(repr = "u16")]
+    #[repr(u16)]
+    enum Attribute {
+        Mtu(u32) = 4,
+        Link {
+            index: u16,
+            #[binbuf(skip = true)]
+            cached: bool,
+        } = 7,
+        Empty,
+    }
+
+    let mut b = Writer::new();
+
+    assert_eq!(Attribute::Mtu(1500).write::<BigEndian>(&mut b), Ok(6));
+    assert_eq!(
+        Attribute::Link {
+            index: 2,
+            cached: true
+        }
+        .write::<BigEndian>(&mut b),
+        Ok(4)
+    );
+    assert_eq!(Attribute::Empty.write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(b.bytes(), &[0, 4, 0, 0, 0x05, 0xDC, 0, 7, 0, 2, 0, 8]);
+
+    let mut r = Reader::new(b.bytes());
+    assert_eq!(
+        Attribute::read::<BigEndian>(&mut r),
+        Ok(Attribute::Mtu(1500))
+    );
+    assert_eq!(
+        Attribute::read::<BigEndian>(&mut r),
+        Ok(Attribute::Link {
+            index: 2,
+            cached: false
+        })
+    );
+    assert_eq!(Attribute::read::<BigEndian>(&mut r), Ok(Attribute::Empty));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_enum_generics() {
+    use binbuf::{BigEndian, Write, Writer};
+
+    #[derive(Write)]
+    #[binbuf(repr = "u16")]
+    #[repr(u16)]
+    enum Attribute<T> {
+        Value(T) = 1,
+        Empty = 2,
+    }
+
+    let mut b = Writer::new();
+
+    assert_eq!(Attribute::Value(7_u32).write::<BigEndian>(&mut b), Ok(6));
+    assert_eq!(Attribute::<u32>::Empty.write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(b.bytes(), &[0, 1, 0, 0, 0, 7, 0, 2]);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_writeable_derive_external_tag() {
+    use std::net::Ipv4Addr;
+
+    use binbuf::{BigEndian, Read, ReadPayload, Reader, Write, WritePayload, Writer};
+
+    #[derive(Debug, PartialEq, Read, Write)]
+    #[binbuf(repr = "u16", tag = "external")]
+    #[repr(u16)]
+    enum RData {
+        A(Ipv4Addr) = 1,
+        Mx { preference: u16, exchange: u32 } = 15,
+    }
+
+    let mx = RData::Mx {
+        preference: 10,
+        exchange: 0x01020304,
+    };
+
+    let mut b = Writer::new();
+
+    // The tag is written by the caller, like the type field of DNS records
+    assert_eq!(mx.tag(), 15);
+    assert_eq!(mx.tag().write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(mx.write::<BigEndian>(&mut b), Ok(6));
+    assert_eq!(
+        RData::A(Ipv4Addr::LOCALHOST).write::<BigEndian>(&mut b),
+        Ok(4)
+    );
+    assert_eq!(b.bytes(), &[0, 15, 0, 10, 1, 2, 3, 4, 127, 0, 0, 1]);
+
+    let mut r = Reader::new(&b.bytes()[2..]);
+    assert_eq!(RData::read_payload::<BigEndian>(15, &mut r), Ok(mx));
+    assert_eq!(
+        RData::read_payload::<BigEndian>(1, &mut r),
+        Ok(RData::A(Ipv4Addr::LOCALHOST))
+    );
+}
@@ -0,0 +1,180 @@
+use binbuf::{write::WriteError, Reader, Writer};
+
+#[test]
+fn test_write_domain_name() {
+    let mut b = Writer::new();
+
+    match b.write_domain_name(&["example", "com"]) {
+        Ok(n) => {
+            assert_eq!(n, 13);
+            assert_eq!(
+                b.bytes(),
+                &[7, 101, 120, 97, 109, 112, 108, 101, 3, 99, 111, 109, 0]
+            );
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn test_write_domain_name_root() {
+    let mut b = Writer::new();
+    let labels: [&str; 0] = [];
+
+    assert_eq!(b.write_domain_name(&labels), Ok(1));
+    assert_eq!(b.bytes(), &[0]);
+}
+
+#[test]
+fn test_write_domain_name_uncompressed() {
+    let mut b = Writer::new();
+
+    b.write_domain_name(&["com"]).unwrap();
+    b.write_domain_name(&["com"]).unwrap();
+
+    assert_eq!(b.bytes(), &[3, 99, 111, 109, 0, 3, 99, 111, 109, 0]);
+}
+
+#[test]
+fn test_write_domain_name_compressed() {
+    let mut b = Writer::new();
+    b.enable_compression();
+
+    assert_eq!(b.write_domain_name(&["com"]), Ok(5));
+    assert_eq!(b.write_domain_name(&["example", "com"]), Ok(10));
+    assert_eq!(b.write_domain_name(&["www", "example", "com"]), Ok(6));
+    assert_eq!(b.write_domain_name(&["www", "example", "com"]), Ok(2));
+    assert_eq!(b.write_domain_name(&["www", "example", "org"]), Ok(17));
+
+    assert_eq!(
+        b.bytes(),
+        &[
+            3, 99, 111, 109, 0, // com
+            7, 101, 120, 97, 109, 112, 108, 101, 0xC0, 0, // example.com
+            3, 119, 119, 119, 0xC0, 5, // www.example.com
+            0xC0, 15, // www.example.com
+            3, 119, 119, 119, 7, 101, 120, 97, 109, 112, 108, 101, 3, 111, 114, 103,
+            0, // www.example.org
+        ]
+    );
+}
+
+#[test]
+fn test_write_domain_name_roundtrip() {
+    let names: [&[&str]; 4] = [
+        &["example", "com"],
+        &["mail", "example", "com"],
+        &["example", "org"],
+        &["mail", "example", "com"],
+    ];
+
+    let mut b = Writer::new();
+    b.enable_compression();
+
+    for name in names {
+        b.write_domain_name(name).unwrap();
+    }
+
+    let mut r = Reader::new(b.bytes());
+
+    for name in names {
+        let labels: Vec<Vec<u8>> = name.iter().map(|l| l.as_bytes().to_vec()).collect();
+        assert_eq!(r.read_domain_name(), Ok(labels));
+    }
+
+    assert!(r.is_empty());
+}
+
+#[test]
+fn test_write_domain_name_reset_compression() {
+    let mut b = Writer::new();
+    b.enable_compression();
+
+    b.write_domain_name(&["com"]).unwrap();
+    b.push(69);
+    b.reset_compression();
+
+    assert_eq!(b.write_domain_name(&["example", "com"]), Ok(13));
+    assert_eq!(b.write_domain_name(&["com"]), Ok(2));
+
+    // The pointer is relative to the start of the second message
+    assert_eq!(&b.bytes()[19..], &[0xC0, 8]);
+}
+
+#[test]
+fn test_write_domain_name_clear() {
+    let mut b = Writer::new();
+    b.enable_compression();
+
+    b.write_domain_name(&["com"]).unwrap();
+    b.clear();
+
+    assert!(b.compression_enabled());
+    assert_eq!(b.write_domain_name(&["com"]), Ok(5));
+}
+
+#[test]
+fn test_write_domain_name_span() {
+    let mut b = Writer::new();
+    b.enable_compression();
+    b.enter();
+
+    b.write_domain_name(&["example", "com"]).unwrap();
+
+    {
+        b.enter();
+        b.write_domain_name(&["www", "example", "com"]).unwrap();
+        assert_eq!(b.exit(), 6);
+    }
+
+    assert_eq!(b.exit(), 19);
+}
+
+#[test]
+fn test_write_domain_name_invalid_label() {
+    let mut b = Writer::new();
+    let long = "a".repeat(64);
+
+    assert_eq!(
+        b.write_domain_name(&["example", ""]),
+        Err(WriteError::InvalidLabelLength { len: 0 })
+    );
+    assert_eq!(
+        b.write_domain_name(&[long.as_str()]),
+        Err(WriteError::InvalidLabelLength { len: 64 })
+    );
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_write_domain_name_too_long() {
+    let mut b = Writer::new();
+    let label = "a".repeat(63);
+
+    assert_eq!(
+        b.write_domain_name(&[&label, &label, &label, &label]),
+        Err(WriteError::NameTooLong)
+    );
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_write_domain_name_buffer_full() {
+    let mut data = [0u8; 20];
+    let mut b = Writer::new_fixed(&mut data);
+    b.enable_compression();
+    b.enter();
+
+    b.write_domain_name(&["example", "com"]).unwrap();
+    assert_eq!(
+        b.write_domain_name(&["www", "example", "org"]),
+        Err(WriteError::BufferFull {
+            nbytes: 17,
+            remaining: 7
+        })
+    );
+
+    // Nothing of the failed name is written
+    assert_eq!(b.bytes(), b"\x07example\x03com\x00");
+    assert_eq!(b.exit(), 13);
+}
@@ -0,0 +1,91 @@
+use binbuf::{
+    read::Reader,
+    write::{FloatPolicy, WriteError, Writer},
+    BigEndian, LittleEndian, Read, Write,
+};
+
+#[test]
+fn test_write_f32() {
+    let mut b = Writer::new();
+
+    assert_eq!(std::f32::consts::PI.write::<BigEndian>(&mut b), Ok(4));
+    assert_eq!(std::f32::consts::PI.write::<LittleEndian>(&mut b), Ok(4));
+    assert_eq!(b.bytes(), &[0x40, 0x49, 0x0F, 0xDB, 0xDB, 0x0F, 0x49, 0x40]);
+}
+
+#[test]
+fn test_write_f64() {
+    let mut b = Writer::new();
+
+    assert_eq!(std::f64::consts::PI.write::<BigEndian>(&mut b), Ok(8));
+    assert_eq!(b.bytes(), &[0x40, 0x09, 0x21, 0xFB, 0x54, 0x44, 0x2D, 0x18]);
+}
+
+#[test]
+fn test_write_float_roundtrip() {
+    let values = [0.0, -0.0, 1.5, -1e300, f64::INFINITY, f64::MIN_POSITIVE];
+    let mut b = Writer::new();
+
+    for v in values {
+        v.write::<LittleEndian>(&mut b).unwrap();
+    }
+
+    let mut r = Reader::new(b.bytes());
+    for v in values {
+        let read = f64::read::<LittleEndian>(&mut r).unwrap();
+        assert_eq!(read.to_bits(), v.to_bits());
+    }
+}
+
+#[test]
+fn test_write_float_preserve() {
+    let mut b = Writer::new();
+    assert_eq!(b.float_policy(), FloatPolicy::Preserve);
+
+    (-0.0f32).write::<BigEndian>(&mut b).unwrap();
+    f32::from_bits(0x7F800001)
+        .write::<BigEndian>(&mut b)
+        .unwrap();
+    assert_eq!(b.bytes(), &[0x80, 0, 0, 0, 0x7F, 0x80, 0, 1]);
+}
+
+#[test]
+fn test_write_float_canonicalize() {
+    let mut b = Writer::new();
+    b.set_float_policy(FloatPolicy::Canonicalize);
+
+    (-0.0f64).write::<BigEndian>(&mut b).unwrap();
+    f64::from_bits(0xFFF0000000000001)
+        .write::<BigEndian>(&mut b)
+        .unwrap();
+    (-1.0f64).write::<BigEndian>(&mut b).unwrap();
+
+    assert_eq!(
+        b.bytes(),
+        &[
+            0, 0, 0, 0, 0, 0, 0, 0, // +0.0
+            0x7F, 0xF8, 0, 0, 0, 0, 0, 0, // canonical NaN
+            0xBF, 0xF0, 0, 0, 0, 0, 0, 0, // -1.0
+        ]
+    );
+}
+
+#[test]
+fn test_write_float_reject() {
+    let mut b = Writer::new();
+    b.set_float_policy(FloatPolicy::Reject);
+
+    assert_eq!(
+        f32::from_bits(0x7FC00001).write::<BigEndian>(&mut b),
+        Err(WriteError::NonCanonicalNan)
+    );
+    assert_eq!(
+        (-0.0f64).write::<LittleEndian>(&mut b),
+        Err(WriteError::NegativeZero)
+    );
+    assert!(b.is_empty());
+
+    assert_eq!(f32::NAN.write::<BigEndian>(&mut b), Ok(4));
+    assert_eq!(0.0f32.write::<BigEndian>(&mut b), Ok(4));
+    assert_eq!(b.bytes(), &[0x7F, 0xC0, 0, 0, 0, 0, 0, 0]);
+}
@@ -0,0 +1,77 @@
+use binbuf::{write::WriteError, BigEndian, LittleEndian, Write, Writer};
+
+#[test]
+fn test_write_length_u8() {
+    let mut b = Writer::new();
+
+    let slot = b.reserve_length::<u8, BigEndian>().unwrap();
+    b.write([69, 88, 65, 77]);
+
+    assert_eq!(b.patch_length(slot), Ok(4));
+    assert_eq!(b.bytes(), &[4, 69, 88, 65, 77]);
+}
+
+#[test]
+fn test_write_length_u16_le() {
+    let mut b = Writer::new();
+    b.push(42);
+
+    let slot = b.reserve_length::<u16, LittleEndian>().unwrap();
+    1163411789u32.write::<BigEndian>(&mut b).unwrap();
+
+    assert_eq!(b.patch_length(slot), Ok(4));
+    assert_eq!(b.bytes(), &[42, 4, 0, 69, 88, 65, 77]);
+}
+
+#[test]
+fn test_write_length_u32_empty() {
+    let mut b = Writer::new();
+
+    let slot = b.reserve_length::<u32, BigEndian>().unwrap();
+
+    assert_eq!(b.patch_length(slot), Ok(0));
+    assert_eq!(b.bytes(), &[0, 0, 0, 0]);
+}
+
+#[test]
+fn test_write_length_nested() {
+    let mut b = Writer::new();
+    b.enter();
+
+    // Type-length-value with a nested TLV as the value
+    b.push(1);
+    let outer = b.reserve_length::<u16, BigEndian>().unwrap();
+    b.push(2);
+    let inner = b.reserve_length::<u8, BigEndian>().unwrap();
+    b.write([69, 88]);
+
+    assert_eq!(b.patch_length(inner), Ok(2));
+    assert_eq!(b.patch_length(outer), Ok(4));
+    assert_eq!(b.exit(), 7);
+
+    assert_eq!(b.bytes(), &[1, 0, 4, 2, 2, 69, 88]);
+}
+
+#[test]
+fn test_write_length_overflow() {
+    let mut b = Writer::new();
+
+    let slot = b.reserve_length::<u8, BigEndian>().unwrap();
+    b.write([0; 256]);
+
+    assert_eq!(
+        b.patch_length(slot),
+        Err(WriteError::LengthOverflow { len: 256 })
+    );
+}
+
+#[test]
+fn test_write_length_unbalanced_span() {
+    let mut b = Writer::new();
+
+    let slot = b.reserve_length::<u8, BigEndian>().unwrap();
+    b.enter();
+    b.push(69);
+
+    assert_eq!(b.patch_length(slot), Err(WriteError::InvalidLengthSlot));
+}
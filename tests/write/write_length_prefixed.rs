@@ -0,0 +1,79 @@
+use binbuf::{
+    varint::Uleb128,
+    write::{WriteError, Writer},
+    BigEndian, LittleEndian,
+};
+
+#[test]
+fn test_write_length_prefixed() {
+    let mut b = Writer::new();
+
+    assert_eq!(
+        b.write_length_prefixed::<u8, BigEndian>([69, 88], None),
+        Ok(3)
+    );
+    assert_eq!(
+        b.write_length_prefixed::<u16, BigEndian>([65, 77], None),
+        Ok(4)
+    );
+    assert_eq!(
+        b.write_length_prefixed::<u32, LittleEndian>([80], None),
+        Ok(5)
+    );
+    assert_eq!(b.bytes(), &[2, 69, 88, 0, 2, 65, 77, 1, 0, 0, 0, 80]);
+}
+
+#[test]
+fn test_write_length_prefixed_varint() {
+    let mut b = Writer::new();
+    let s = vec![69; 300];
+
+    assert_eq!(
+        b.write_length_prefixed::<Uleb128, BigEndian>(&s, None),
+        Ok(302)
+    );
+    assert_eq!(&b.bytes()[..3], &[0xAC, 0x02, 69]);
+}
+
+#[test]
+fn test_write_length_prefixed_max_len() {
+    let mut b = Writer::new();
+
+    assert_eq!(
+        b.write_length_prefixed::<u16, BigEndian>([69, 88, 65], Some(2)),
+        Err(WriteError::MaxLengthOverflow)
+    );
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_write_length_prefixed_overflow() {
+    let mut b = Writer::new();
+
+    assert_eq!(
+        b.write_length_prefixed::<u8, BigEndian>(vec![0; 256], None),
+        Err(WriteError::LengthOverflow { len: 256 })
+    );
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_write_length_prefixed_fixed_buffer() {
+    let mut buf = [0u8; 4];
+    let mut b = Writer::new_fixed(&mut buf);
+
+    assert_eq!(
+        b.write_length_prefixed::<u16, BigEndian>([69, 88, 65], None),
+        Err(WriteError::BufferFull {
+            nbytes: 5,
+            remaining: 4
+        })
+    );
+    assert!(b.is_empty());
+
+    assert_eq!(
+        b.write_length_prefixed::<u16, BigEndian>([69, 88], None),
+        Ok(4)
+    );
+    assert_eq!(b.bytes(), &[0, 2, 69, 88]);
+}
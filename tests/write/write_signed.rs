@@ -0,0 +1,52 @@
+use binbuf::{write::Writer, BigEndian, LittleEndian, Write};
+
+#[test]
+fn test_write_i8() {
+    let mut b = Writer::new();
+
+    assert_eq!((-1i8).write::<BigEndian>(&mut b), Ok(1));
+    assert_eq!(i8::MIN.write::<BigEndian>(&mut b), Ok(1));
+    assert_eq!(b.bytes(), &[0xFF, 0x80]);
+}
+
+#[test]
+fn test_write_i16() {
+    let mut b = Writer::new();
+
+    assert_eq!((-2i16).write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!((-2i16).write::<LittleEndian>(&mut b), Ok(2));
+    assert_eq!(b.bytes(), &[0xFF, 0xFE, 0xFE, 0xFF]);
+}
+
+#[test]
+fn test_write_i32() {
+    let mut b = Writer::new();
+
+    assert_eq!((-123i32).write::<BigEndian>(&mut b), Ok(4));
+    assert_eq!(b.bytes(), &[0xFF, 0xFF, 0xFF, 0x85]);
+}
+
+#[test]
+fn test_write_i64() {
+    let mut b = Writer::new();
+
+    assert_eq!(i64::MIN.write::<LittleEndian>(&mut b), Ok(8));
+    assert_eq!(b.bytes(), &[0, 0, 0, 0, 0, 0, 0, 0x80]);
+}
+
+#[test]
+fn test_write_i128() {
+    let mut b = Writer::new();
+
+    assert_eq!((-1i128).write::<BigEndian>(&mut b), Ok(16));
+    assert_eq!(b.bytes(), &[0xFF; 16]);
+}
+
+#[test]
+fn test_write_multi_i16() {
+    let d = vec![-1i16, 1, i16::MIN];
+    let mut b = Writer::new();
+
+    assert_eq!(d.write::<BigEndian>(&mut b), Ok(6));
+    assert_eq!(b.bytes(), &[0xFF, 0xFF, 0x00, 0x01, 0x80, 0x00]);
+}
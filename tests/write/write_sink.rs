@@ -0,0 +1,139 @@
+use std::io::{self, BufWriter};
+
+use binbuf::{
+    write::{WriteError, Writer},
+    BigEndian, BitOrder, Write,
+};
+
+/// A sink which accepts at most `limit` bytes.
+struct Limited {
+    buf: Vec<u8>,
+    limit: usize,
+}
+
+impl io::Write for Limited {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = buf.len().min(self.limit - self.buf.len());
+        if n == 0 {
+            return Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"));
+        }
+
+        self.buf.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_write_sink() {
+    let mut sink = Vec::new();
+    let mut b = Writer::new_sink(&mut sink);
+
+    assert_eq!(17752u16.write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(vec![16717u16, 20556].write::<BigEndian>(&mut b), Ok(4));
+    assert_eq!(b.len(), 6);
+    assert_eq!(b.bytes(), &[]);
+    assert_eq!(b.remaining(), None);
+
+    drop(b);
+    assert_eq!(sink, vec![69, 88, 65, 77, 80, 76]);
+}
+
+#[test]
+fn test_write_sink_buf_writer() {
+    let mut sink = BufWriter::new(Vec::new());
+    let mut b = Writer::new_sink(&mut sink);
+
+    b.write_char_string([69, 88], None).unwrap();
+    b.flush().unwrap();
+
+    drop(b);
+    assert_eq!(sink.get_ref(), &vec![2, 69, 88]);
+}
+
+#[test]
+fn test_write_sink_io_error() {
+    let mut sink = Limited {
+        buf: Vec::new(),
+        limit: 3,
+    };
+    let mut b = Writer::new_sink(&mut sink);
+
+    assert_eq!(17752u16.write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(
+        17752u16.write::<BigEndian>(&mut b),
+        Err(WriteError::Io {
+            kind: io::ErrorKind::BrokenPipe,
+            message: String::from("closed")
+        })
+    );
+}
+
+#[test]
+fn test_write_sink_span() {
+    let mut sink = Vec::new();
+    let mut b = Writer::new_sink(&mut sink);
+
+    b.enter();
+    b.push(69);
+
+    let mut bits = b.bits(BitOrder::MsbFirst);
+    bits.write_bits(0x158, 12).unwrap();
+    assert_eq!(bits.finish_padded(), Ok(2));
+
+    assert_eq!(b.exit(), 3);
+
+    drop(b);
+    assert_eq!(sink, vec![69, 0x15, 0x80]);
+}
+
+#[test]
+fn test_write_sink_domain_name() {
+    let mut sink = Vec::new();
+    let mut b = Writer::new_sink(&mut sink);
+    b.enable_compression();
+
+    b.write_domain_name(&["example", "com"]).unwrap();
+    b.write_domain_name(&["www", "example", "com"]).unwrap();
+
+    drop(b);
+    assert_eq!(&sink[13..], &[3, 119, 119, 119, 0xC0, 0]);
+}
+
+#[test]
+fn test_write_sink_length() {
+    let mut sink = Vec::new();
+    let mut b = Writer::new_sink(&mut sink);
+
+    assert_eq!(
+        b.reserve_length::<u16, BigEndian>().unwrap_err(),
+        WriteError::PatchUnsupported
+    );
+    assert!(b.is_empty());
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_write_sink_derive() {
+    #[derive(Write)]
+    struct Data {
+        v1: u16,
+        v2: u32,
+    }
+
+    let mut sink = Vec::new();
+    let mut b = Writer::new_sink(&mut sink);
+
+    let data = Data {
+        v1: 17752,
+        v2: 1095585868,
+    };
+
+    assert_eq!(data.write::<BigEndian>(&mut b), Ok(6));
+
+    drop(b);
+    assert_eq!(sink, vec![69, 88, 65, 77, 80, 76]);
+}
@@ -0,0 +1,60 @@
+use binbuf::{
+    string::{StringPolicy, StringPrefix},
+    write::{WriteError, Writer},
+    BigEndian, LittleEndian, Write,
+};
+
+#[test]
+fn test_write_string_default() {
+    let mut b = Writer::new();
+
+    assert_eq!(String::from("example").write::<BigEndian>(&mut b), Ok(7));
+    assert_eq!(
+        String::from("grüß").write::<BigEndian>(&mut b),
+        Err(WriteError::NonAsciiData)
+    );
+    assert_eq!(b.bytes(), b"example");
+}
+
+#[test]
+fn test_write_str_utf8() {
+    let mut b = Writer::new();
+    b.set_string_policy(StringPolicy::Utf8);
+
+    assert_eq!("grüß".write::<BigEndian>(&mut b), Ok(6));
+    assert_eq!(b.bytes(), "grüß".as_bytes());
+}
+
+#[test]
+fn test_write_string_prefix() {
+    let mut b = Writer::new();
+    b.set_string_prefix(StringPrefix::U16);
+
+    assert_eq!("ssh".write::<LittleEndian>(&mut b), Ok(5));
+    assert_eq!(String::new().write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(b.bytes(), &[3, 0, 115, 115, 104, 0, 0]);
+
+    b.set_string_prefix(StringPrefix::Uleb128);
+    assert_eq!("a".repeat(200).write::<BigEndian>(&mut b), Ok(202));
+    assert_eq!(&b.bytes()[7..9], &[0xC8, 0x01]);
+}
+
+#[test]
+fn test_write_str_prefixed() {
+    let mut b = Writer::new();
+
+    assert_eq!(
+        b.write_str_prefixed::<u8, BigEndian>("grüß", None, StringPolicy::Ascii),
+        Err(WriteError::NonAsciiData)
+    );
+    assert_eq!(
+        b.write_str_prefixed::<u8, BigEndian>("ssh", Some(2), StringPolicy::Ascii),
+        Err(WriteError::MaxLengthOverflow)
+    );
+    assert!(b.is_empty());
+
+    assert_eq!(
+        b.write_str_prefixed::<u8, BigEndian>("grüß", None, StringPolicy::Lossy),
+        Ok(7)
+    );
+}
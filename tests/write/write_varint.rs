@@ -0,0 +1,92 @@
+use binbuf::{
+    varint::{QuicVarInt, Sleb128, Uleb128, ZigZag},
+    write::{WriteError, Writer},
+    BigEndian, LittleEndian, Write,
+};
+
+#[test]
+fn test_write_uleb128() {
+    let mut b = Writer::new();
+
+    assert_eq!(Uleb128(0).write::<BigEndian>(&mut b), Ok(1));
+    assert_eq!(Uleb128(624485).write::<LittleEndian>(&mut b), Ok(3));
+    assert_eq!(Uleb128(u64::MAX).write::<BigEndian>(&mut b), Ok(10));
+    assert_eq!(
+        b.bytes(),
+        &[0x00, 0xE5, 0x8E, 0x26, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]
+    );
+}
+
+#[test]
+fn test_write_sleb128() {
+    let mut b = Writer::new();
+
+    for v in [-1, 63, 64, -64, -65, -123456] {
+        Sleb128(v).write::<BigEndian>(&mut b).unwrap();
+    }
+
+    assert_eq!(
+        b.bytes(),
+        &[0x7F, 0x3F, 0xC0, 0x00, 0x40, 0xBF, 0x7F, 0xC0, 0xBB, 0x78]
+    );
+}
+
+#[test]
+fn test_write_sleb128_limits() {
+    let mut b = Writer::new();
+
+    assert_eq!(Sleb128(i64::MIN).write::<BigEndian>(&mut b), Ok(10));
+    assert_eq!(Sleb128(i64::MAX).write::<BigEndian>(&mut b), Ok(10));
+    assert_eq!(
+        b.bytes(),
+        &[
+            0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, // i64::MIN
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, // i64::MAX
+        ]
+    );
+}
+
+#[test]
+fn test_write_zigzag() {
+    let mut b = Writer::new();
+
+    for v in [0, -1, 1, -2, 150] {
+        ZigZag(v).write::<BigEndian>(&mut b).unwrap();
+    }
+
+    assert_eq!(b.bytes(), &[0x00, 0x01, 0x02, 0x03, 0xAC, 0x02]);
+}
+
+#[test]
+fn test_write_quic_varint() {
+    let mut b = Writer::new();
+
+    assert_eq!(
+        QuicVarInt(151288809941952652).write::<BigEndian>(&mut b),
+        Ok(8)
+    );
+    assert_eq!(QuicVarInt(494878333).write::<BigEndian>(&mut b), Ok(4));
+    assert_eq!(QuicVarInt(15293).write::<BigEndian>(&mut b), Ok(2));
+    assert_eq!(QuicVarInt(37).write::<BigEndian>(&mut b), Ok(1));
+    assert_eq!(
+        b.bytes(),
+        &[
+            0xC2, 0x19, 0x7C, 0x5E, 0xFF, 0x14, 0xE8, 0x8C, 0x9D, 0x7F, 0x3E, 0x7D, 0x7B, 0xBD,
+            0x25
+        ]
+    );
+}
+
+#[test]
+fn test_write_quic_varint_overflow() {
+    let mut b = Writer::new();
+
+    assert_eq!(
+        QuicVarInt(1 << 62).write::<BigEndian>(&mut b),
+        Err(WriteError::VarIntOverflow { value: 1 << 62 })
+    );
+    assert!(b.is_empty());
+
+    assert_eq!(QuicVarInt((1 << 62) - 1).write::<BigEndian>(&mut b), Ok(8));
+    assert_eq!(b.bytes(), &[0xFF; 8]);
+}